@@ -25,4 +25,98 @@ impl BoundingBox {
             width,
         }
     }
+
+    /// The axis-aligned box enclosing this box after mapping its eight
+    /// corners through `t` (e.g. when a box computed in a surface's local
+    /// frame needs to be expressed in world space after a rotation).
+    pub fn transformed(&self, t: &crate::transform::Transform) -> BoundingBox {
+        let lo = self.lower_left_corner;
+        let hi = self.upper_right_corner;
+        let corners = [
+            [lo[0], lo[1], lo[2]],
+            [lo[0], lo[1], hi[2]],
+            [lo[0], hi[1], lo[2]],
+            [lo[0], hi[1], hi[2]],
+            [hi[0], lo[1], lo[2]],
+            [hi[0], lo[1], hi[2]],
+            [hi[0], hi[1], lo[2]],
+            [hi[0], hi[1], hi[2]],
+        ];
+
+        let mut lower = [f64::INFINITY; 3];
+        let mut upper = [f64::NEG_INFINITY; 3];
+        for corner in corners {
+            let mapped = t.apply_point(corner);
+            for axis in 0..3 {
+                lower[axis] = lower[axis].min(mapped[axis]);
+                upper[axis] = upper[axis].max(mapped[axis]);
+            }
+        }
+        BoundingBox::new(lower, upper)
+    }
+
+    /// The smallest axis-aligned box enclosing both `self` and `other`.
+    pub fn union(&self, other: &BoundingBox) -> BoundingBox {
+        let lower = [
+            self.lower_left_corner[0].min(other.lower_left_corner[0]),
+            self.lower_left_corner[1].min(other.lower_left_corner[1]),
+            self.lower_left_corner[2].min(other.lower_left_corner[2]),
+        ];
+        let upper = [
+            self.upper_right_corner[0].max(other.upper_right_corner[0]),
+            self.upper_right_corner[1].max(other.upper_right_corner[1]),
+            self.upper_right_corner[2].max(other.upper_right_corner[2]),
+        ];
+        BoundingBox::new(lower, upper)
+    }
+
+    /// Whether `point` lies within this box (inclusive of the boundary).
+    pub fn contains_point(&self, point: (f64, f64, f64)) -> bool {
+        point.0 >= self.lower_left_corner[0]
+            && point.0 <= self.upper_right_corner[0]
+            && point.1 >= self.lower_left_corner[1]
+            && point.1 <= self.upper_right_corner[1]
+            && point.2 >= self.lower_left_corner[2]
+            && point.2 <= self.upper_right_corner[2]
+    }
+
+    /// Whether `ray` intersects this box, via the standard slab test. Used as
+    /// a fast reject before walking every triangle of a mesh surface.
+    pub fn intersects_ray(&self, ray: &crate::region::Ray) -> bool {
+        let origin = [ray.origin.0, ray.origin.1, ray.origin.2];
+        let direction = [ray.direction.0, ray.direction.1, ray.direction.2];
+        let mut t_min = 0.0_f64;
+        let mut t_max = f64::INFINITY;
+        for axis in 0..3 {
+            let lo = self.lower_left_corner[axis];
+            let hi = self.upper_right_corner[axis];
+            if direction[axis].abs() < 1e-12 {
+                if origin[axis] < lo || origin[axis] > hi {
+                    return false;
+                }
+            } else {
+                let mut t1 = (lo - origin[axis]) / direction[axis];
+                let mut t2 = (hi - origin[axis]) / direction[axis];
+                if t1 > t2 {
+                    std::mem::swap(&mut t1, &mut t2);
+                }
+                t_min = t_min.max(t1);
+                t_max = t_max.min(t2);
+                if t_min > t_max {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// The sphere circumscribing this box: centered at the box midpoint,
+    /// with a radius of half the box's diagonal.
+    pub fn bounding_sphere(&self) -> crate::bounding_sphere::BoundingSphere {
+        let radius = 0.5
+            * crate::ops::sqrt(
+                self.width[0] * self.width[0] + self.width[1] * self.width[1] + self.width[2] * self.width[2],
+            );
+        crate::bounding_sphere::BoundingSphere::new(self.center, radius)
+    }
 }
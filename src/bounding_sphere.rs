@@ -0,0 +1,124 @@
+use crate::region::Ray;
+
+/// Center separations below this are treated as "the same point", so
+/// `enclosing` doesn't divide by a near-zero distance.
+const EPSILON: f64 = 1e-9;
+
+/// A sphere that encloses some geometry, used as a cheaper pre-filter than an
+/// axis-aligned box for spherical or rotated regions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundingSphere {
+    pub center: [f64; 3],
+    pub radius: f64,
+}
+
+impl BoundingSphere {
+    pub fn new(center: [f64; 3], radius: f64) -> Self {
+        BoundingSphere { center, radius }
+    }
+
+    pub fn contains_point(&self, point: (f64, f64, f64)) -> bool {
+        let dx = point.0 - self.center[0];
+        let dy = point.1 - self.center[1];
+        let dz = point.2 - self.center[2];
+        dx * dx + dy * dy + dz * dz <= self.radius * self.radius
+    }
+
+    /// The smallest sphere that covers both `self` and `other`, used to
+    /// combine two regions' bounding spheres at a union without falling back
+    /// to a looser AABB circumscription.
+    pub fn enclosing(&self, other: &BoundingSphere) -> BoundingSphere {
+        let dx = other.center[0] - self.center[0];
+        let dy = other.center[1] - self.center[1];
+        let dz = other.center[2] - self.center[2];
+        let distance = crate::ops::sqrt(dx * dx + dy * dy + dz * dz);
+
+        if distance + other.radius <= self.radius {
+            return self.clone();
+        }
+        if distance + self.radius <= other.radius {
+            return other.clone();
+        }
+
+        let radius = 0.5 * (distance + self.radius + other.radius);
+        if distance < EPSILON {
+            // Concentric (or nearly so) spheres of similar radius: centering
+            // the combined sphere anywhere works equally well.
+            return BoundingSphere::new(self.center, radius);
+        }
+        let t = (radius - self.radius) / distance;
+        let center = [
+            self.center[0] + t * dx,
+            self.center[1] + t * dy,
+            self.center[2] + t * dz,
+        ];
+        BoundingSphere::new(center, radius)
+    }
+
+    /// Cheap reject test: project the center onto the ray (assumed to have a
+    /// normalized direction) and compare the perpendicular distance to the
+    /// radius. Intended as a pre-filter before an exact
+    /// `Region::distance_to_boundary` query, not an exact intersection test.
+    pub fn intersects_ray(&self, ray: &Ray) -> bool {
+        let to_center = [
+            self.center[0] - ray.origin.0,
+            self.center[1] - ray.origin.1,
+            self.center[2] - ray.origin.2,
+        ];
+        let dir = [ray.direction.0, ray.direction.1, ray.direction.2];
+        let t = to_center[0] * dir[0] + to_center[1] * dir[1] + to_center[2] * dir[2];
+        let closest = [
+            ray.origin.0 + t * dir[0],
+            ray.origin.1 + t * dir[1],
+            ray.origin.2 + t * dir[2],
+        ];
+        let dx = closest[0] - self.center[0];
+        let dy = closest[1] - self.center[1];
+        let dz = closest[2] - self.center[2];
+        dx * dx + dy * dy + dz * dz <= self.radius * self.radius
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains_point() {
+        let sphere = BoundingSphere::new([0.0, 0.0, 0.0], 2.0);
+        assert!(sphere.contains_point((1.0, 0.0, 0.0)));
+        assert!(!sphere.contains_point((3.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_enclosing_covers_both_disjoint_spheres() {
+        let a = BoundingSphere::new([0.0, 0.0, 0.0], 1.0);
+        let b = BoundingSphere::new([10.0, 0.0, 0.0], 1.0);
+        let combined = a.enclosing(&b);
+
+        assert!(combined.contains_point((0.0, 0.0, 0.0)));
+        assert!(combined.contains_point((10.0, 0.0, 0.0)));
+        // The smallest sphere spanning [-1, 11] on the x-axis is centered at
+        // 5 with radius 6, not some looser bound.
+        assert!((combined.center[0] - 5.0).abs() < 1e-9);
+        assert!((combined.radius - 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_enclosing_returns_larger_sphere_when_nested() {
+        let outer = BoundingSphere::new([0.0, 0.0, 0.0], 5.0);
+        let inner = BoundingSphere::new([1.0, 0.0, 0.0], 1.0);
+        assert_eq!(outer.enclosing(&inner), outer);
+        assert_eq!(inner.enclosing(&outer), outer);
+    }
+
+    #[test]
+    fn test_intersects_ray() {
+        let sphere = BoundingSphere::new([5.0, 0.0, 0.0], 1.0);
+        let hit = Ray { origin: (0.0, 0.0, 0.0), direction: (1.0, 0.0, 0.0) };
+        assert!(sphere.intersects_ray(&hit));
+
+        let miss = Ray { origin: (0.0, 0.0, 0.0), direction: (0.0, 1.0, 0.0) };
+        assert!(!sphere.intersects_ray(&miss));
+    }
+}
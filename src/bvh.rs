@@ -0,0 +1,184 @@
+use crate::bounding_box::BoundingBox;
+
+/// A bounding-volume hierarchy over a set of indexed boxes, used by
+/// `Geometry::find_cell` to prune cells whose AABB doesn't contain the query
+/// point instead of checking every cell's region.
+#[derive(Clone, Debug)]
+pub struct Bvh {
+    root: Option<BvhNode>,
+}
+
+#[derive(Clone, Debug)]
+enum BvhNode {
+    Leaf {
+        bbox: BoundingBox,
+        index: usize,
+    },
+    Internal {
+        bbox: BoundingBox,
+        left: Box<BvhNode>,
+        right: Box<BvhNode>,
+    },
+}
+
+impl BvhNode {
+    fn bbox(&self) -> &BoundingBox {
+        match self {
+            BvhNode::Leaf { bbox, .. } => bbox,
+            BvhNode::Internal { bbox, .. } => bbox,
+        }
+    }
+
+    fn query_point(&self, point: (f64, f64, f64), out: &mut Vec<usize>) {
+        if !self.bbox().contains_point(point) {
+            return;
+        }
+        match self {
+            BvhNode::Leaf { index, .. } => out.push(*index),
+            BvhNode::Internal { left, right, .. } => {
+                left.query_point(point, out);
+                right.query_point(point, out);
+            }
+        }
+    }
+
+    fn query_ray(&self, ray: &crate::region::Ray, out: &mut Vec<usize>) {
+        if !self.bbox().intersects_ray(ray) {
+            return;
+        }
+        match self {
+            BvhNode::Leaf { index, .. } => out.push(*index),
+            BvhNode::Internal { left, right, .. } => {
+                left.query_ray(ray, out);
+                right.query_ray(ray, out);
+            }
+        }
+    }
+}
+
+impl Bvh {
+    /// Build a BVH over `boxes`, a list of (index, bounding box) pairs.
+    ///
+    /// Splits top-down along the axis of largest centroid spread at the
+    /// median centroid, storing the union box at each internal node.
+    pub fn build(boxes: &[(usize, BoundingBox)]) -> Self {
+        let entries: Vec<(usize, BoundingBox, [f64; 3])> = boxes
+            .iter()
+            .map(|(index, bbox)| (*index, bbox.clone(), bbox.center))
+            .collect();
+        Bvh {
+            root: build_node(entries),
+        }
+    }
+
+    /// Collect the indices of every leaf whose box contains `point`.
+    pub fn query_point(&self, point: (f64, f64, f64), out: &mut Vec<usize>) {
+        if let Some(root) = &self.root {
+            root.query_point(point, out);
+        }
+    }
+
+    /// Collect the indices of every leaf whose box `ray` could hit, pruning
+    /// subtrees whose union box the ray misses entirely. A node's box is
+    /// already the cheap pre-filter volume for its subtree, so no separate
+    /// bounding sphere is kept per node.
+    pub fn query_ray(&self, ray: &crate::region::Ray, out: &mut Vec<usize>) {
+        if let Some(root) = &self.root {
+            root.query_ray(ray, out);
+        }
+    }
+}
+
+fn build_node(mut entries: Vec<(usize, BoundingBox, [f64; 3])>) -> Option<BvhNode> {
+    if entries.is_empty() {
+        return None;
+    }
+    if entries.len() == 1 {
+        let (index, bbox, _) = entries.into_iter().next().unwrap();
+        return Some(BvhNode::Leaf { bbox, index });
+    }
+
+    let mut mins = [f64::INFINITY; 3];
+    let mut maxs = [f64::NEG_INFINITY; 3];
+    for (_, _, centroid) in &entries {
+        for axis in 0..3 {
+            mins[axis] = mins[axis].min(centroid[axis]);
+            maxs[axis] = maxs[axis].max(centroid[axis]);
+        }
+    }
+    let spreads = [maxs[0] - mins[0], maxs[1] - mins[1], maxs[2] - mins[2]];
+    let axis = if spreads[0] >= spreads[1] && spreads[0] >= spreads[2] {
+        0
+    } else if spreads[1] >= spreads[2] {
+        1
+    } else {
+        2
+    };
+
+    entries.sort_by(|a, b| a.2[axis].partial_cmp(&b.2[axis]).unwrap());
+    let mid = entries.len() / 2;
+    let right_entries = entries.split_off(mid);
+    let left = build_node(entries);
+    let right = build_node(right_entries);
+
+    match (left, right) {
+        (Some(l), Some(r)) => {
+            let bbox = l.bbox().union(r.bbox());
+            Some(BvhNode::Internal {
+                bbox,
+                left: Box::new(l),
+                right: Box::new(r),
+            })
+        }
+        (Some(l), None) => Some(l),
+        (None, Some(r)) => Some(r),
+        (None, None) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_point_finds_containing_boxes() {
+        let boxes = vec![
+            (0, BoundingBox::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0])),
+            (1, BoundingBox::new([5.0, 5.0, 5.0], [6.0, 6.0, 6.0])),
+            (2, BoundingBox::new([-1.0, -1.0, -1.0], [2.0, 2.0, 2.0])),
+        ];
+        let bvh = Bvh::build(&boxes);
+
+        let mut hits = Vec::new();
+        bvh.query_point((0.5, 0.5, 0.5), &mut hits);
+        hits.sort_unstable();
+        assert_eq!(hits, vec![0, 2]);
+
+        let mut miss = Vec::new();
+        bvh.query_point((100.0, 100.0, 100.0), &mut miss);
+        assert!(miss.is_empty());
+    }
+
+    #[test]
+    fn test_query_ray_prunes_missed_subtrees() {
+        let boxes = vec![
+            (0, BoundingBox::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0])),
+            (1, BoundingBox::new([5.0, 5.0, 5.0], [6.0, 6.0, 6.0])),
+        ];
+        let bvh = Bvh::build(&boxes);
+
+        let mut hits = Vec::new();
+        bvh.query_ray(
+            &crate::region::Ray { origin: (-1.0, 0.5, 0.5), direction: (1.0, 0.0, 0.0) },
+            &mut hits,
+        );
+        assert_eq!(hits, vec![0]);
+
+        let mut miss = Vec::new();
+        bvh.query_ray(
+            &crate::region::Ray { origin: (-1.0, 10.0, 10.0), direction: (1.0, 0.0, 0.0) },
+            &mut miss,
+        );
+        assert!(miss.is_empty());
+    }
+}
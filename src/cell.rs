@@ -15,17 +15,30 @@ pub struct Cell {
 }
 
 impl Cell {
-    /// Find the closest surface of this cell to a point along a direction (stub)
-    pub fn closest_surface(&self, _point: (f64, f64, f64), _direction: (f64, f64, f64)) -> Option<&crate::surface::Surface> {
-        // TODO: Implement actual surface intersection logic
-        None
+    /// Find the closest surface of this cell to a point along a direction,
+    /// i.e. the surface this cell's region would next cross if a particle at
+    /// `point` travelled along `direction`.
+    pub fn closest_surface(&self, point: (f64, f64, f64), direction: (f64, f64, f64)) -> Option<Arc<crate::surface::Surface>> {
+        self.distance_and_surface(point, direction).map(|(_, surface)| surface)
     }
 
-    /// Compute the distance to the closest surface from a point along a direction (stub)
-    pub fn distance_to_surface(&self, _point: (f64, f64, f64), _direction: (f64, f64, f64)) -> Option<f64> {
-        // TODO: Implement actual distance calculation
-        None
+    /// Compute the distance to the closest surface from a point along a direction.
+    pub fn distance_to_surface(&self, point: (f64, f64, f64), direction: (f64, f64, f64)) -> Option<f64> {
+        self.distance_and_surface(point, direction).map(|(distance, _)| distance)
     }
+
+    /// Distance to the nearest boundary crossing of this cell's region from
+    /// `point` along `direction`, together with the surface that was hit.
+    /// This is the core per-cell ray query a transport loop advances through.
+    pub fn distance_to_boundary(&self, point: (f64, f64, f64), direction: (f64, f64, f64)) -> Option<(f64, Arc<crate::surface::Surface>)> {
+        self.distance_and_surface(point, direction)
+    }
+
+    fn distance_and_surface(&self, point: (f64, f64, f64), direction: (f64, f64, f64)) -> Option<(f64, Arc<crate::surface::Surface>)> {
+        let ray = crate::region::Ray { origin: point, direction };
+        self.region.distance_to_boundary(&ray)
+    }
+
     /// Create a new cell with a region and optional material (fill)
     pub fn new(cell_id: u32, region: Region, name: Option<String>, material: Option<Material>) -> Self {
         Cell {
@@ -63,6 +76,7 @@ mod tests {
                 radius: 1.0,
             },
             boundary_type: BoundaryType::default(),
+            inverse_transform: None,
         };
         let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(s1)));
 
@@ -88,6 +102,7 @@ mod tests {
                 radius: 2.0,
             },
             boundary_type: BoundaryType::default(),
+            inverse_transform: None,
         };
         let s2 = Surface {
             surface_id: 2,
@@ -98,6 +113,7 @@ mod tests {
                 radius: 2.0,
             },
             boundary_type: BoundaryType::default(),
+            inverse_transform: None,
         };
         let region1 = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(s1)));
         let region2 = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(s2)));
@@ -120,6 +136,7 @@ mod tests {
                 radius: 2.0,
             },
             boundary_type: BoundaryType::default(),
+            inverse_transform: None,
         };
         let s2 = Surface {
             surface_id: 2,
@@ -130,6 +147,7 @@ mod tests {
                 radius: 2.0,
             },
             boundary_type: BoundaryType::default(),
+            inverse_transform: None,
         };
         let region1 = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(s1)));
         let region2 = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(s2)));
@@ -152,6 +170,7 @@ mod tests {
                 radius: 2.0,
             },
             boundary_type: BoundaryType::default(),
+            inverse_transform: None,
         };
         let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(s1)));
         let region_complement = region.complement();
@@ -171,6 +190,7 @@ mod tests {
                 d: 2.1,
             }, // x = 2.1
             boundary_type: BoundaryType::default(),
+            inverse_transform: None,
         };
         let s2 = Surface {
             surface_id: 6,
@@ -181,6 +201,7 @@ mod tests {
                 d: -2.1,
             }, // x = -2.1
             boundary_type: BoundaryType::default(),
+            inverse_transform: None,
         };
         let s3 = Surface {
             surface_id: 1,
@@ -191,6 +212,7 @@ mod tests {
                 radius: 4.2,
             },
             boundary_type: BoundaryType::default(),
+            inverse_transform: None,
         };
         let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(s1)))
             .intersection(&Region::new_from_halfspace(HalfspaceType::Above(Arc::new(
@@ -226,6 +248,7 @@ mod tests {
                 radius: 2.0,
             },
             boundary_type: BoundaryType::default(),
+            inverse_transform: None,
         };
         let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(sphere)));
     let cell = Cell::new(1, region, None, None);
@@ -245,6 +268,7 @@ mod tests {
                 radius: 2.0,
             },
             boundary_type: BoundaryType::default(),
+            inverse_transform: None,
         };
         let s2 = Surface {
             surface_id: 2,
@@ -255,6 +279,7 @@ mod tests {
                 radius: 2.0,
             },
             boundary_type: BoundaryType::default(),
+            inverse_transform: None,
         };
         let region1 = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(s1.clone())));
         let region2 = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(s2.clone())));
@@ -284,9 +309,53 @@ mod tests {
                 radius: 2.0,
             },
             boundary_type: BoundaryType::default(),
+            inverse_transform: None,
         };
         let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(sphere)));
     let cell = Cell::new(1, region, Some("fuel".to_string()), None);
         assert_eq!(cell.name, Some("fuel".to_string()));
     }
+
+    #[test]
+    fn test_cell_distance_to_surface() {
+        // Sphere of radius 2 at the origin, ray starting outside heading in.
+        let sphere = Surface {
+            surface_id: 7,
+            kind: SurfaceKind::Sphere {
+                x0: 0.0,
+                y0: 0.0,
+                z0: 0.0,
+                radius: 2.0,
+            },
+            boundary_type: BoundaryType::default(),
+            inverse_transform: None,
+        };
+        let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(sphere)));
+        let cell = Cell::new(1, region, None, None);
+
+        let distance = cell.distance_to_surface((-5.0, 0.0, 0.0), (1.0, 0.0, 0.0)).unwrap();
+        assert!((distance - 3.0).abs() < 1e-9);
+
+        let surface = cell.closest_surface((-5.0, 0.0, 0.0), (1.0, 0.0, 0.0)).unwrap();
+        assert_eq!(surface.surface_id, 7);
+
+        // A ray heading away from the cell never crosses its boundary.
+        assert!(cell.distance_to_surface((-5.0, 0.0, 0.0), (-1.0, 0.0, 0.0)).is_none());
+    }
+
+    #[test]
+    fn test_cell_distance_to_boundary_matches_distance_to_surface() {
+        let sphere = Surface {
+            surface_id: 7,
+            kind: SurfaceKind::Sphere { x0: 0.0, y0: 0.0, z0: 0.0, radius: 2.0 },
+            boundary_type: BoundaryType::default(),
+            inverse_transform: None,
+        };
+        let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(sphere)));
+        let cell = Cell::new(1, region, None, None);
+
+        let (distance, surface) = cell.distance_to_boundary((-5.0, 0.0, 0.0), (1.0, 0.0, 0.0)).unwrap();
+        assert!((distance - 3.0).abs() < 1e-9);
+        assert_eq!(surface.surface_id, 7);
+    }
 }
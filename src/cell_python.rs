@@ -71,4 +71,10 @@ impl PyCell {
     pub fn contains(&self, x: f64, y: f64, z: f64) -> bool {
         self.inner.contains((x, y, z))
     }
+
+    /// Distance along a ray to the nearest crossing of this cell's boundary,
+    /// and the `surface_id` of the surface that was hit.
+    pub fn distance_to_boundary(&self, origin: (f64, f64, f64), direction: (f64, f64, f64)) -> Option<(f64, usize)> {
+        self.inner.distance_to_boundary(origin, direction).map(|(t, surface)| (t, surface.surface_id))
+    }
 }
@@ -1,13 +1,220 @@
+use crate::bounding_box::BoundingBox;
+use crate::bounding_sphere::BoundingSphere;
+use crate::bvh::Bvh;
 use crate::cell::Cell;
 
 /// Geometry is a collection of cells for Monte Carlo transport
+#[derive(Clone)]
 pub struct Geometry {
     pub cells: Vec<Cell>,
+    bvh: Bvh,
+    /// Cells whose region has an unbounded AABB (e.g. half-space-only
+    /// regions) can't be placed in the BVH, so they're checked on every query.
+    unbounded_cells: Vec<usize>,
+    /// Per-cell bounding sphere, indexed like `cells`. Cheaper than the exact
+    /// `contains` check, so it's tried first for BVH-sourced candidates.
+    /// Unbounded cells get an all-space placeholder here and are never
+    /// filtered by it, since a half-space region has no meaningful sphere.
+    bounding_spheres: Vec<BoundingSphere>,
 }
 
 impl Geometry {
+    /// Build a geometry from `cells`, indexing their region bounding boxes in
+    /// a BVH so `find_cell` doesn't need a linear scan.
+    pub fn new(cells: Vec<Cell>) -> Self {
+        let mut bounded = Vec::new();
+        let mut unbounded_cells = Vec::new();
+        let mut bounding_spheres = Vec::with_capacity(cells.len());
+        for (index, cell) in cells.iter().enumerate() {
+            let bbox = cell.region.bounding_box();
+            if is_unbounded(&bbox) {
+                unbounded_cells.push(index);
+                // A half-space-only region's sphere would have a NaN center
+                // (0.5 * (-inf + inf)), so use an explicit all-space stand-in
+                // instead. It's never consulted for these indices anyway.
+                bounding_spheres.push(BoundingSphere::new([0.0, 0.0, 0.0], f64::INFINITY));
+            } else {
+                bounding_spheres.push(cell.region.bounding_sphere());
+                bounded.push((index, bbox));
+            }
+        }
+        Geometry {
+            cells,
+            bvh: Bvh::build(&bounded),
+            unbounded_cells,
+            bounding_spheres,
+        }
+    }
+
     /// Find the first cell containing the given point, or None if not found
     pub fn find_cell(&self, point: (f64, f64, f64)) -> Option<&Cell> {
-        self.cells.iter().find(|cell| cell.contains(point))
+        let mut candidates = Vec::new();
+        self.bvh.query_point(point, &mut candidates);
+        candidates.retain(|&index| self.bounding_spheres[index].contains_point(point));
+        candidates.extend_from_slice(&self.unbounded_cells);
+        candidates.sort_unstable();
+        candidates
+            .into_iter()
+            .find(|&index| self.cells[index].contains(point))
+            .map(|index| &self.cells[index])
+    }
+
+    /// Find every cell whose bounding box `ray` could hit, plus every
+    /// unbounded cell. A superset of the cells `ray` actually crosses,
+    /// intended as a candidate list for ray tracing rather than an exact
+    /// intersection test.
+    pub fn cells_along_ray(&self, ray: &crate::region::Ray) -> Vec<&Cell> {
+        let mut candidates = Vec::new();
+        self.bvh.query_ray(ray, &mut candidates);
+        candidates.extend_from_slice(&self.unbounded_cells);
+        candidates.sort_unstable();
+        candidates.dedup();
+        candidates.into_iter().map(|index| &self.cells[index]).collect()
+    }
+
+    /// Distance to the nearest boundary crossing of whichever cell contains
+    /// `point`, together with the surface that was hit. A transport loop
+    /// advances a particle by repeatedly calling this, moving to the hit
+    /// distance, and calling it again for the next cell.
+    pub fn distance_to_boundary(&self, point: (f64, f64, f64), direction: (f64, f64, f64)) -> Option<(f64, std::sync::Arc<crate::surface::Surface>)> {
+        self.find_cell(point)?.distance_to_boundary(point, direction)
+    }
+}
+
+fn is_unbounded(bbox: &BoundingBox) -> bool {
+    bbox.lower_left_corner.iter().any(|v| !v.is_finite())
+        || bbox.upper_right_corner.iter().any(|v| !v.is_finite())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::region::{HalfspaceType, Region};
+    use crate::surface::{BoundaryType, Surface, SurfaceKind};
+    use std::sync::Arc;
+
+    fn sphere_cell(id: u32, x0: f64, y0: f64, z0: f64, radius: f64) -> Cell {
+        let surface = Surface {
+            surface_id: id as usize,
+            kind: SurfaceKind::Sphere { x0, y0, z0, radius },
+            boundary_type: BoundaryType::default(),
+            inverse_transform: None,
+        };
+        let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(surface)));
+        Cell::new(id, region, None, None)
+    }
+
+    #[test]
+    fn test_find_cell_via_bvh() {
+        let geometry = Geometry::new(vec![
+            sphere_cell(1, 0.0, 0.0, 0.0, 1.0),
+            sphere_cell(2, 10.0, 0.0, 0.0, 1.0),
+            sphere_cell(3, -10.0, 0.0, 0.0, 1.0),
+        ]);
+
+        assert_eq!(geometry.find_cell((0.0, 0.0, 0.0)).unwrap().cell_id, 1);
+        assert_eq!(geometry.find_cell((10.0, 0.0, 0.0)).unwrap().cell_id, 2);
+        assert!(geometry.find_cell((5.0, 0.0, 0.0)).is_none());
+    }
+
+    #[test]
+    fn test_find_cell_with_unbounded_halfspace() {
+        // A half-space-only cell has an infinite AABB and must still be found.
+        let plane = Surface {
+            surface_id: 1,
+            kind: SurfaceKind::Plane { a: 1.0, b: 0.0, c: 0.0, d: 0.0 },
+            boundary_type: BoundaryType::default(),
+            inverse_transform: None,
+        };
+        let half_space_cell = Cell::new(
+            1,
+            Region::new_from_halfspace(HalfspaceType::Above(Arc::new(plane))),
+            None,
+            None,
+        );
+        let geometry = Geometry::new(vec![half_space_cell, sphere_cell(2, 0.0, 0.0, 0.0, 1.0)]);
+
+        assert_eq!(geometry.find_cell((100.0, 0.0, 0.0)).unwrap().cell_id, 1);
+        assert!(geometry.find_cell((-100.0, 0.0, 0.0)).is_none());
+    }
+
+    #[test]
+    fn test_cells_along_ray_prunes_boxes_the_ray_misses() {
+        let geometry = Geometry::new(vec![
+            sphere_cell(1, 0.0, 0.0, 0.0, 1.0),
+            sphere_cell(2, 10.0, 0.0, 0.0, 1.0),
+            sphere_cell(3, 0.0, 10.0, 0.0, 1.0),
+        ]);
+
+        let ray = crate::region::Ray { origin: (-5.0, 0.0, 0.0), direction: (1.0, 0.0, 0.0) };
+        let mut ids: Vec<u32> = geometry.cells_along_ray(&ray).iter().map(|c| c.cell_id).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_cells_along_ray_includes_unbounded_cells() {
+        let plane = Surface {
+            surface_id: 1,
+            kind: SurfaceKind::Plane { a: 1.0, b: 0.0, c: 0.0, d: 0.0 },
+            boundary_type: BoundaryType::default(),
+            inverse_transform: None,
+        };
+        let half_space_cell = Cell::new(
+            1,
+            Region::new_from_halfspace(HalfspaceType::Above(Arc::new(plane))),
+            None,
+            None,
+        );
+        let geometry = Geometry::new(vec![half_space_cell, sphere_cell(2, 10.0, 0.0, 0.0, 1.0)]);
+
+        // A ray that never comes near either bounded cell's box still sees
+        // the unbounded half-space cell, since it's exempt from BVH pruning.
+        let ray = crate::region::Ray { origin: (0.0, 100.0, 0.0), direction: (0.0, 1.0, 0.0) };
+        let ids: Vec<u32> = geometry.cells_along_ray(&ray).iter().map(|c| c.cell_id).collect();
+        assert_eq!(ids, vec![1]);
+    }
+
+    #[test]
+    fn test_distance_to_boundary_finds_containing_cells_surface() {
+        let geometry = Geometry::new(vec![
+            sphere_cell(1, 0.0, 0.0, 0.0, 1.0),
+            sphere_cell(2, 10.0, 0.0, 0.0, 1.0),
+        ]);
+
+        let (distance, surface) = geometry.distance_to_boundary((0.0, 0.0, 0.0), (1.0, 0.0, 0.0)).unwrap();
+        assert!((distance - 1.0).abs() < 1e-9);
+        assert_eq!(surface.surface_id, 1);
+
+        // A point outside every cell has no containing cell to advance through.
+        assert!(geometry.distance_to_boundary((5.0, 0.0, 0.0), (1.0, 0.0, 0.0)).is_none());
+    }
+
+    #[test]
+    fn test_find_cell_mixed_transform_region_not_pruned_by_bounding_sphere() {
+        // A cell whose region mixes a translated surface with an
+        // untransformed one: the per-cell bounding sphere used to pre-filter
+        // `find_cell` must not be computed too small from this, or a
+        // genuinely-contained point is wrongly retained-out.
+        let sphere = Surface {
+            surface_id: 1,
+            kind: SurfaceKind::Sphere { x0: 0.0, y0: 0.0, z0: 0.0, radius: 10.0 },
+            boundary_type: BoundaryType::default(),
+            inverse_transform: None,
+        }
+        .translate([-100.0, 0.0, 0.0]);
+        let plane = Surface {
+            surface_id: 2,
+            kind: SurfaceKind::Plane { a: 1.0, b: 0.0, c: 0.0, d: -200.0 },
+            boundary_type: BoundaryType::default(),
+            inverse_transform: None,
+        };
+        let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(sphere)))
+            .intersection(&Region::new_from_halfspace(HalfspaceType::Above(Arc::new(plane))));
+        let mixed_cell = Cell::new(1, region, None, None);
+
+        let geometry = Geometry::new(vec![mixed_cell, sphere_cell(2, 50.0, 0.0, 0.0, 1.0)]);
+
+        assert_eq!(geometry.find_cell((-100.0, 0.0, 0.0)).unwrap().cell_id, 1);
     }
 }
@@ -15,10 +15,17 @@ impl PyGeometry {
     #[new]
     pub fn new(cells: Vec<PyCell>) -> Self {
         let rust_cells = cells.into_iter().map(|pycell| pycell.inner).collect();
-        PyGeometry { inner: Geometry { cells: rust_cells } }
+        PyGeometry { inner: Geometry::new(rust_cells) }
     }
 
     pub fn find_cell(&self, x: f64, y: f64, z: f64) -> Option<PyCell> {
         self.inner.find_cell((x, y, z)).cloned().map(|cell| PyCell { inner: cell })
     }
+
+    /// Distance along a ray to the nearest crossing of the boundary of
+    /// whichever cell contains `origin`, and the `surface_id` of the surface
+    /// that was hit. Lets a transport loop advance a particle cell-by-cell.
+    pub fn distance_to_boundary(&self, origin: (f64, f64, f64), direction: (f64, f64, f64)) -> Option<(f64, usize)> {
+        self.inner.distance_to_boundary(origin, direction).map(|(t, surface)| (t, surface.surface_id))
+    }
 }
@@ -1,20 +1,28 @@
 pub mod bounding_box;
+pub mod bounding_sphere;
+pub mod bvh;
 pub mod cell;
+pub mod geometry;
+pub mod ops;
 pub mod region;
 pub mod surface;
+pub mod transform;
 
 // Only include Python-specific code when the pyo3 feature is enabled
 #[cfg(feature = "pyo3")]
 pub mod cell_python;
 #[cfg(feature = "pyo3")]
+pub mod geometry_python;
+#[cfg(feature = "pyo3")]
 pub mod region_python;
 #[cfg(feature = "pyo3")]
 pub mod surface_python;
 
 // Re-export the public API for Rust users
 pub use cell::Cell;
-pub use region::{HalfspaceType, Region, RegionExpr};
+pub use region::{HalfspaceType, Ray, Region, RegionExpr, Relation};
 pub use surface::{BoundaryType, Surface};
+pub use transform::Transform;
 
 // Only export the Python module when the pyo3 feature is enabled
 #[cfg(feature = "pyo3")]
@@ -27,9 +35,10 @@ fn constructive_solid_geometry_for_mc(_py: Python, m: &PyModule) -> PyResult<()>
     m.add_class::<region_python::PyHalfspace>()?;
     m.add_class::<cell_python::PyCell>()?;
     m.add_class::<cell_python::PyMaterial>()?;
+    m.add_class::<geometry_python::PyGeometry>()?;
     m.add_class::<surface_python::PyBoundaryType>()?;
     // Expose surface constructors at top level for OpenMC-style API
-    use surface_python::{Cylinder, Plane, Sphere, XPlane, YPlane, ZCylinder, ZPlane};
+    use surface_python::{Cone, Cylinder, Plane, Quadric, Sphere, XPlane, YPlane, ZCylinder, ZPlane};
     m.add_function(wrap_pyfunction!(XPlane, m)?)?;
     m.add_function(wrap_pyfunction!(YPlane, m)?)?;
     m.add_function(wrap_pyfunction!(ZPlane, m)?)?;
@@ -37,5 +46,7 @@ fn constructive_solid_geometry_for_mc(_py: Python, m: &PyModule) -> PyResult<()>
     m.add_function(wrap_pyfunction!(Cylinder, m)?)?;
     m.add_function(wrap_pyfunction!(ZCylinder, m)?)?;
     m.add_function(wrap_pyfunction!(Plane, m)?)?;
+    m.add_function(wrap_pyfunction!(Cone, m)?)?;
+    m.add_function(wrap_pyfunction!(Quadric, m)?)?;
     Ok(())
 }
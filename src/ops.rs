@@ -0,0 +1,64 @@
+//! Thin wrappers over the floating-point transcendental/root operations used
+//! throughout the geometry code. By default they forward to `std`; with the
+//! `libm` Cargo feature enabled they forward to `libm` instead.
+//!
+//! Enabling `libm` trades a little speed for bit-for-bit determinism across
+//! platforms and toolchains, which matters for Monte Carlo results that need
+//! to be compared run-to-run or reproduced while tracking down a regression.
+//!
+//! Every surface kind's `evaluate`/`normal` math routes through here, down to
+//! the cone's half-angle `sin_cos` and the quadratic-root solver used by
+//! sphere/cylinder/cone/quadric ray intersection — none of it calls `f64`
+//! methods directly.
+
+#[cfg(not(feature = "libm"))]
+pub fn sqrt(x: f64) -> f64 {
+    x.sqrt()
+}
+
+#[cfg(feature = "libm")]
+pub fn sqrt(x: f64) -> f64 {
+    libm::sqrt(x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub fn powi(x: f64, n: i32) -> f64 {
+    x.powi(n)
+}
+
+#[cfg(feature = "libm")]
+pub fn powi(x: f64, n: i32) -> f64 {
+    libm::pow(x, n as f64)
+}
+
+#[cfg(not(feature = "libm"))]
+pub fn sin_cos(x: f64) -> (f64, f64) {
+    x.sin_cos()
+}
+
+#[cfg(feature = "libm")]
+pub fn sin_cos(x: f64) -> (f64, f64) {
+    (libm::sin(x), libm::cos(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sqrt_matches_std() {
+        assert_eq!(sqrt(4.0), 2.0);
+    }
+
+    #[test]
+    fn test_sin_cos_matches_std() {
+        let (s, c) = sin_cos(0.0);
+        assert_eq!(s, 0.0);
+        assert_eq!(c, 1.0);
+    }
+
+    #[test]
+    fn test_powi_matches_std() {
+        assert_eq!(powi(2.0, 3), 8.0);
+    }
+}
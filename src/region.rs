@@ -1,13 +1,38 @@
 // ...existing code...
-use crate::surface::Surface;
+use crate::surface::{Surface, SurfaceKind};
+use crate::transform::Transform;
 // ...existing code...
+use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Distances smaller than this are treated as "on the surface" rather than a
+/// genuine crossing, so a particle sitting on a boundary doesn't immediately
+/// re-intersect the surface it just came from.
+const EPSILON: f64 = 1e-9;
+
+/// A ray used to query the distance to the next surface crossing of a region,
+/// e.g. for advancing a Monte Carlo particle through the geometry.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ray {
+    pub origin: (f64, f64, f64),
+    pub direction: (f64, f64, f64),
+}
+
 #[derive(Clone)]
 pub struct Region {
     pub expr: RegionExpr,
 }
 
+/// The relationship of a box to a region's boundary, used by acceleration
+/// structures (voxel meshes, BVHs) to prune subtrees that are known not to
+/// straddle the surface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    Inside,
+    Outside,
+    Crossing,
+}
+
 #[derive(Clone)]
 pub enum HalfspaceType {
     Above(Arc<Surface>),
@@ -47,6 +72,48 @@ impl Region {
             expr: RegionExpr::Complement(Box::new(self.expr.clone())),
         }
     }
+
+    /// Subtract `other` from this region, i.e. `self ∩ ~other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement())
+    }
+
+    /// Return a new region with every surface repositioned by `t`, e.g. to
+    /// instance a unit cell built once at a different location/orientation.
+    pub fn transformed(&self, t: &Transform) -> Self {
+        Region {
+            expr: self.expr.transformed(t),
+        }
+    }
+
+    /// Rotate this region by `matrix`, e.g. to orient a unit cell before
+    /// instancing it in a lattice.
+    pub fn rotate(&self, matrix: [[f64; 3]; 3]) -> Self {
+        self.transformed(&Transform::rotation(matrix))
+    }
+
+    /// Translate this region by `offset`, e.g. to instance a unit cell at a
+    /// different lattice position.
+    pub fn translate(&self, offset: [f64; 3]) -> Self {
+        self.transformed(&Transform::translation(offset))
+    }
+
+    /// A bounding sphere for this region: exact when the region is the
+    /// inside of a single untransformed sphere; for a union, the smallest sphere covering both
+    /// branches' bounding spheres; otherwise (an intersection, a lone
+    /// non-sphere halfspace, or a complement) the sphere circumscribing the
+    /// AABB, which for an intersection is already the sphere enclosing the
+    /// intersected box since `bounding_box` combines intersected halfspaces
+    /// directly.
+    pub fn bounding_sphere(&self) -> crate::bounding_sphere::BoundingSphere {
+        region_expr_bounding_sphere(&self.expr)
+    }
+
+    /// Conservatively classify whether `bbox` lies entirely inside, entirely
+    /// outside, or straddles this region's boundary.
+    pub fn classify_box(&self, bbox: &crate::bounding_box::BoundingBox) -> Relation {
+        self.expr.classify_box(bbox)
+    }
     
     // Updated contains method: no surface dictionary needed
     pub fn contains(&self, point: (f64, f64, f64)) -> bool {
@@ -60,101 +127,573 @@ impl Region {
 
     pub fn bounding_box(&self) -> crate::bounding_box::BoundingBox {
         use crate::surface::SurfaceKind;
-        let mut x_bounds = (f64::NEG_INFINITY, f64::INFINITY);
-        let mut y_bounds = (f64::NEG_INFINITY, f64::INFINITY);
-        let mut z_bounds = (f64::NEG_INFINITY, f64::INFINITY);
 
-        // Collect axis-aligned plane bounds with correct sign convention
-        fn collect_axis_bounds(expr: &RegionExpr,
-                              x_bounds: &mut (f64, f64), y_bounds: &mut (f64, f64), z_bounds: &mut (f64, f64)) {
+        /// Axis-aligned plane bounds and the tightest sphere/triangle/mesh
+        /// bound found for one group of surfaces that all share the same
+        /// `inverse_transform`, so their coefficients live in the same local
+        /// frame and can be intersected before mapping into world space.
+        struct LocalBounds {
+            x: (f64, f64),
+            y: (f64, f64),
+            z: (f64, f64),
+            primitive: Option<([f64; 3], [f64; 3])>,
+        }
+
+        impl LocalBounds {
+            fn new() -> Self {
+                LocalBounds {
+                    x: (f64::NEG_INFINITY, f64::INFINITY),
+                    y: (f64::NEG_INFINITY, f64::INFINITY),
+                    z: (f64::NEG_INFINITY, f64::INFINITY),
+                    primitive: None,
+                }
+            }
+
+            fn add_primitive(&mut self, bounds: ([f64; 3], [f64; 3])) {
+                self.primitive = Some(match self.primitive {
+                    Some((lo, hi)) => (
+                        [lo[0].max(bounds.0[0]), lo[1].max(bounds.0[1]), lo[2].max(bounds.0[2])],
+                        [hi[0].min(bounds.1[0]), hi[1].min(bounds.1[1]), hi[2].min(bounds.1[2])],
+                    ),
+                    None => bounds,
+                });
+            }
+
+            fn into_box(self) -> crate::bounding_box::BoundingBox {
+                let lower = [
+                    self.primitive.map_or(self.x.0, |b| self.x.0.max(b.0[0])),
+                    self.primitive.map_or(self.y.0, |b| self.y.0.max(b.0[1])),
+                    self.primitive.map_or(self.z.0, |b| self.z.0.max(b.0[2])),
+                ];
+                let upper = [
+                    self.primitive.map_or(self.x.1, |b| self.x.1.min(b.1[0])),
+                    self.primitive.map_or(self.y.1, |b| self.y.1.min(b.1[1])),
+                    self.primitive.map_or(self.z.1, |b| self.z.1.min(b.1[2])),
+                ];
+                crate::bounding_box::BoundingBox::new(lower, upper)
+            }
+        }
+
+        fn primitive_bounds(kind: &SurfaceKind) -> Option<([f64; 3], [f64; 3])> {
+            match kind {
+                SurfaceKind::Sphere { x0, y0, z0, radius } => Some((
+                    [*x0 - *radius, *y0 - *radius, *z0 - *radius],
+                    [*x0 + *radius, *y0 + *radius, *z0 + *radius],
+                )),
+                SurfaceKind::Triangle { v0, v1, v2 } => Some((
+                    [v0[0].min(v1[0]).min(v2[0]), v0[1].min(v1[1]).min(v2[1]), v0[2].min(v1[2]).min(v2[2])],
+                    [v0[0].max(v1[0]).max(v2[0]), v0[1].max(v1[1]).max(v2[1]), v0[2].max(v1[2]).max(v2[2])],
+                )),
+                SurfaceKind::Mesh { bbox: Some(bbox), .. } => Some((bbox.lower_left_corner, bbox.upper_right_corner)),
+                _ => None,
+            }
+        }
+
+        // Walk the intersection, bucketing each surface's contribution by
+        // its own `inverse_transform` instead of assuming one transform for
+        // the whole tree: a region mixing a transformed surface with an
+        // untransformed one (e.g. a translated sphere intersected with a
+        // fixed plane) would otherwise have the untransformed surface's
+        // bounds combined as if it lived in the transformed surface's local
+        // frame, silently producing a box that doesn't contain the region.
+        fn collect(expr: &RegionExpr, groups: &mut Vec<(Option<Transform>, LocalBounds)>) {
             match expr {
                 RegionExpr::Intersection(a, b) => {
-                    collect_axis_bounds(a, x_bounds, y_bounds, z_bounds);
-                    collect_axis_bounds(b, x_bounds, y_bounds, z_bounds);
+                    collect(a, groups);
+                    collect(b, groups);
                 }
                 RegionExpr::Halfspace(hs) => {
-                    match hs {
-                        HalfspaceType::Below(surf) => {
-                            match &surf.kind {
-                                SurfaceKind::Plane { a, b, c, d } => {
-                                    if *a == 1.0 && *b == 0.0 && *c == 0.0 {
-                                        x_bounds.1 = x_bounds.1.min(*d); // x < d
-                                    } else if *a == 0.0 && *b == 1.0 && *c == 0.0 {
-                                        y_bounds.1 = y_bounds.1.min(*d); // y < d
-                                    } else if *a == 0.0 && *b == 0.0 && *c == 1.0 {
-                                        z_bounds.1 = z_bounds.1.min(*d); // z < d
-                                    }
+                    let (surf, is_above) = match hs {
+                        HalfspaceType::Above(surf) => (surf, true),
+                        HalfspaceType::Below(surf) => (surf, false),
+                    };
+                    let index = match groups.iter().position(|(t, _)| t == &surf.inverse_transform) {
+                        Some(i) => i,
+                        None => {
+                            groups.push((surf.inverse_transform.clone(), LocalBounds::new()));
+                            groups.len() - 1
+                        }
+                    };
+                    let group = &mut groups[index].1;
+                    match &surf.kind {
+                        SurfaceKind::Plane { a, b, c, d } => {
+                            if is_above {
+                                if *a == 1.0 && *b == 0.0 && *c == 0.0 {
+                                    group.x.0 = group.x.0.max(*d); // x > d
+                                } else if *a == 0.0 && *b == 1.0 && *c == 0.0 {
+                                    group.y.0 = group.y.0.max(*d); // y > d
+                                } else if *a == 0.0 && *b == 0.0 && *c == 1.0 {
+                                    group.z.0 = group.z.0.max(*d); // z > d
                                 }
-                                _ => {}
+                            } else if *a == 1.0 && *b == 0.0 && *c == 0.0 {
+                                group.x.1 = group.x.1.min(*d); // x < d
+                            } else if *a == 0.0 && *b == 1.0 && *c == 0.0 {
+                                group.y.1 = group.y.1.min(*d); // y < d
+                            } else if *a == 0.0 && *b == 0.0 && *c == 1.0 {
+                                group.z.1 = group.z.1.min(*d); // z < d
                             }
                         }
-                        HalfspaceType::Above(surf) => {
-                            match &surf.kind {
-                                SurfaceKind::Plane { a, b, c, d } => {
-                                    if *a == 1.0 && *b == 0.0 && *c == 0.0 {
-                                        x_bounds.0 = x_bounds.0.max(*d); // x > d
-                                    } else if *a == 0.0 && *b == 1.0 && *c == 0.0 {
-                                        y_bounds.0 = y_bounds.0.max(*d); // y > d
-                                    } else if *a == 0.0 && *b == 0.0 && *c == 1.0 {
-                                        z_bounds.0 = z_bounds.0.max(*d); // z > d
-                                    }
-                                }
-                                _ => {}
+                        other => {
+                            if let Some(bounds) = primitive_bounds(other) {
+                                group.add_primitive(bounds);
                             }
                         }
                     }
                 }
-                _ => {}
+                RegionExpr::Union(_, _) | RegionExpr::Complement(_) => {}
             }
         }
 
-        collect_axis_bounds(&self.expr, &mut x_bounds, &mut y_bounds, &mut z_bounds);
+        let mut groups = Vec::new();
+        collect(&self.expr, &mut groups);
 
-        // Intersect with sphere bounds if present
-        fn find_sphere_bounds(expr: &RegionExpr) -> Option<([f64; 3], [f64; 3])> {
-            match expr {
-                RegionExpr::Halfspace(hs) => {
-                    match hs {
-                        HalfspaceType::Above(surf) | HalfspaceType::Below(surf) => {
-                            if let SurfaceKind::Sphere { x0, y0, z0, radius } = &surf.kind {
-                                return Some((
-                                    [*x0 - *radius, *y0 - *radius, *z0 - *radius],
-                                    [*x0 + *radius, *y0 + *radius, *z0 + *radius],
-                                ));
-                            } else {
-                                None
-                            }
-                        }
+        if groups.is_empty() {
+            // No halfspace outside a union or complement (e.g. a bare union
+            // or complement): nothing constrains the box on any axis.
+            return crate::bounding_box::BoundingBox::new([f64::NEG_INFINITY; 3], [f64::INFINITY; 3]);
+        }
+
+        // Each group's combined local box was computed entirely within that
+        // group's own frame, so it's safe to map into world space on its
+        // own; the groups are then intersected (componentwise) in world
+        // space rather than all at once in a single assumed-shared frame.
+        let mut boxes = groups.into_iter().map(|(transform, bounds)| {
+            let local_box = bounds.into_box();
+            match transform {
+                Some(inverse_transform) => local_box.transformed(&inverse_transform.inverse()),
+                None => local_box,
+            }
+        });
+
+        let first = boxes.next().unwrap();
+        let combined = boxes.fold(first, |acc, b| {
+            crate::bounding_box::BoundingBox::new(
+                [
+                    acc.lower_left_corner[0].max(b.lower_left_corner[0]),
+                    acc.lower_left_corner[1].max(b.lower_left_corner[1]),
+                    acc.lower_left_corner[2].max(b.lower_left_corner[2]),
+                ],
+                [
+                    acc.upper_right_corner[0].min(b.upper_right_corner[0]),
+                    acc.upper_right_corner[1].min(b.upper_right_corner[1]),
+                    acc.upper_right_corner[2].min(b.upper_right_corner[2]),
+                ],
+            )
+        });
+
+        // If any min > max, the intersected groups don't overlap: empty region.
+        if combined.lower_left_corner[0] > combined.upper_right_corner[0]
+            || combined.lower_left_corner[1] > combined.upper_right_corner[1]
+            || combined.lower_left_corner[2] > combined.upper_right_corner[2]
+        {
+            return crate::bounding_box::BoundingBox::new([f64::INFINITY; 3], [f64::NEG_INFINITY; 3]);
+        }
+
+        combined
+    }
+
+    /// Distance along `ray` to the nearest surface crossing that actually
+    /// enters or leaves this region, together with the surface that was hit.
+    ///
+    /// Returns `None` if the ray never crosses the region's boundary.
+    pub fn distance_to_boundary(&self, ray: &Ray) -> Option<(f64, Arc<Surface>)> {
+        let mut surfaces = Vec::new();
+        collect_surfaces(&self.expr, &mut surfaces);
+
+        let mut candidates: Vec<(f64, Arc<Surface>)> = Vec::new();
+        for surface in surfaces {
+            for t in surface_intersections(&surface, ray) {
+                if t > EPSILON {
+                    candidates.push((t, surface.clone()));
+                }
+            }
+        }
+        candidates.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        let was_inside = self.contains(ray.origin);
+        for (t, surface) in candidates {
+            let hit = (
+                ray.origin.0 + t * ray.direction.0,
+                ray.origin.1 + t * ray.direction.1,
+                ray.origin.2 + t * ray.direction.2,
+            );
+            let just_past = (
+                hit.0 + EPSILON * ray.direction.0,
+                hit.1 + EPSILON * ray.direction.1,
+                hit.2 + EPSILON * ray.direction.2,
+            );
+            if self.contains(just_past) != was_inside {
+                return Some((t, surface));
+            }
+        }
+        None
+    }
+
+    /// Render this region as a compact CSG expression, e.g. `-1 & +2 & ~-3`,
+    /// where a signed number is a surface reference (`-id` for "below",
+    /// `+id` for "above") and `&`/`|`/`~` are intersection/union/complement.
+    /// Round-trips through `from_expression_string`.
+    pub fn to_expression_string(&self) -> String {
+        self.expr.to_expression_string()
+    }
+
+    /// Parse a CSG expression produced by `to_expression_string`, resolving
+    /// surface references against `surfaces` (keyed by `surface_id`).
+    pub fn from_expression_string(
+        s: &str,
+        surfaces: &HashMap<usize, Arc<Surface>>,
+    ) -> Result<Region, ExpressionError> {
+        let tokens = tokenize(s)?;
+        let mut parser = ExprParser { tokens: &tokens, pos: 0, surfaces };
+        let expr = parser.parse_or()?;
+        if parser.pos != tokens.len() {
+            return Err(ExpressionError::Malformed(format!(
+                "unexpected trailing input after position {}",
+                parser.pos
+            )));
+        }
+        Ok(Region { expr })
+    }
+}
+
+/// An error encountered parsing a CSG expression string.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExpressionError {
+    UnknownSurfaceId(usize),
+    Malformed(String),
+}
+
+impl std::fmt::Display for ExpressionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExpressionError::UnknownSurfaceId(id) => {
+                write!(f, "unknown surface id {id} in region expression")
+            }
+            ExpressionError::Malformed(msg) => write!(f, "malformed region expression: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ExpressionError {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    LParen,
+    RParen,
+    And,
+    Or,
+    Not,
+    SurfaceRef { id: usize, is_above: bool },
+}
+
+fn tokenize(s: &str) -> Result<Vec<Token>, ExpressionError> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            '&' => {
+                tokens.push(Token::And);
+                i += 1;
+            }
+            '|' => {
+                tokens.push(Token::Or);
+                i += 1;
+            }
+            '~' => {
+                tokens.push(Token::Not);
+                i += 1;
+            }
+            '+' | '-' => {
+                let is_above = c == '+';
+                let start = i + 1;
+                let mut end = start;
+                while end < chars.len() && chars[end].is_ascii_digit() {
+                    end += 1;
+                }
+                if end == start {
+                    return Err(ExpressionError::Malformed(format!(
+                        "expected digits after '{c}' at position {i}"
+                    )));
+                }
+                let digits: String = chars[start..end].iter().collect();
+                let id: usize = digits.parse().map_err(|_| {
+                    ExpressionError::Malformed(format!("invalid surface id at position {i}"))
+                })?;
+                tokens.push(Token::SurfaceRef { id, is_above });
+                i = end;
+            }
+            other => {
+                return Err(ExpressionError::Malformed(format!(
+                    "unexpected character '{other}' at position {i}"
+                )));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct ExprParser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+    surfaces: &'a HashMap<usize, Arc<Surface>>,
+}
+
+impl<'a> ExprParser<'a> {
+    fn peek(&self) -> Option<Token> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn parse_or(&mut self) -> Result<RegionExpr, ExpressionError> {
+        let mut expr = self.parse_and()?;
+        while self.peek() == Some(Token::Or) {
+            self.pos += 1;
+            let rhs = self.parse_and()?;
+            expr = RegionExpr::Union(Box::new(expr), Box::new(rhs));
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> Result<RegionExpr, ExpressionError> {
+        let mut expr = self.parse_unary()?;
+        while self.peek() == Some(Token::And) {
+            self.pos += 1;
+            let rhs = self.parse_unary()?;
+            expr = RegionExpr::Intersection(Box::new(expr), Box::new(rhs));
+        }
+        Ok(expr)
+    }
+
+    fn parse_unary(&mut self) -> Result<RegionExpr, ExpressionError> {
+        match self.peek() {
+            Some(Token::Not) => {
+                self.pos += 1;
+                Ok(RegionExpr::Complement(Box::new(self.parse_unary()?)))
+            }
+            Some(Token::LParen) => {
+                self.pos += 1;
+                let expr = self.parse_or()?;
+                match self.peek() {
+                    Some(Token::RParen) => {
+                        self.pos += 1;
+                        Ok(expr)
                     }
+                    _ => Err(ExpressionError::Malformed("expected ')'".to_string())),
                 }
-                RegionExpr::Intersection(a, b) | RegionExpr::Union(a, b) => {
-                    find_sphere_bounds(a).or_else(|| find_sphere_bounds(b))
+            }
+            Some(Token::SurfaceRef { id, is_above }) => {
+                self.pos += 1;
+                let surface = self
+                    .surfaces
+                    .get(&id)
+                    .cloned()
+                    .ok_or(ExpressionError::UnknownSurfaceId(id))?;
+                Ok(RegionExpr::Halfspace(if is_above {
+                    HalfspaceType::Above(surface)
+                } else {
+                    HalfspaceType::Below(surface)
+                }))
+            }
+            _ => Err(ExpressionError::Malformed(
+                "expected a surface reference, '~', or '('".to_string(),
+            )),
+        }
+    }
+}
+
+/// Operator precedence used to decide when `to_expression_string` needs to
+/// parenthesize a sub-expression: lower binds more loosely.
+fn precedence(expr: &RegionExpr) -> u8 {
+    match expr {
+        RegionExpr::Union(..) => 1,
+        RegionExpr::Intersection(..) => 2,
+        RegionExpr::Complement(..) => 3,
+        RegionExpr::Halfspace(..) => 4,
+    }
+}
+
+/// A bounding sphere for `expr`, recursing into unions so their combined
+/// sphere is no looser than it needs to be; see `Region::bounding_sphere`.
+fn region_expr_bounding_sphere(expr: &RegionExpr) -> crate::bounding_sphere::BoundingSphere {
+    if let Some(sphere) = single_sphere_bound(expr) {
+        return sphere;
+    }
+    match expr {
+        RegionExpr::Union(a, b) => {
+            region_expr_bounding_sphere(a).enclosing(&region_expr_bounding_sphere(b))
+        }
+        _ => Region { expr: expr.clone() }.bounding_box().bounding_sphere(),
+    }
+}
+
+/// If `expr` is exactly "inside a single sphere", return its exact bounding
+/// sphere (an untransformed sphere halfspace is the only case where the AABB
+/// circumscription would be needlessly loose).
+fn single_sphere_bound(expr: &RegionExpr) -> Option<crate::bounding_sphere::BoundingSphere> {
+    match expr {
+        RegionExpr::Halfspace(HalfspaceType::Below(surf)) => match &surf.kind {
+            SurfaceKind::Sphere { x0, y0, z0, radius } if surf.inverse_transform.is_none() => {
+                Some(crate::bounding_sphere::BoundingSphere::new([*x0, *y0, *z0], *radius))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Walk the boolean tree collecting every surface referenced by a halfspace.
+fn collect_surfaces(expr: &RegionExpr, out: &mut Vec<Arc<Surface>>) {
+    match expr {
+        RegionExpr::Halfspace(hs) => match hs {
+            HalfspaceType::Above(surf) | HalfspaceType::Below(surf) => out.push(surf.clone()),
+        },
+        RegionExpr::Union(a, b) | RegionExpr::Intersection(a, b) => {
+            collect_surfaces(a, out);
+            collect_surfaces(b, out);
+        }
+        RegionExpr::Complement(inner) => collect_surfaces(inner, out),
+    }
+}
+
+/// Candidate distances (possibly negative or non-physical) at which `ray` crosses `surface`.
+pub(crate) fn surface_intersections(surface: &Surface, ray: &Ray) -> Vec<f64> {
+    // Intersect in the surface's local frame; an affine map leaves the ray
+    // parameter unchanged, so the roots are valid world-space distances.
+    let local_ray;
+    let ray = match &surface.inverse_transform {
+        Some(t) => {
+            let o = t.apply_point([ray.origin.0, ray.origin.1, ray.origin.2]);
+            let d = t.apply_vector([ray.direction.0, ray.direction.1, ray.direction.2]);
+            local_ray = Ray { origin: (o[0], o[1], o[2]), direction: (d[0], d[1], d[2]) };
+            &local_ray
+        }
+        None => ray,
+    };
+    let (ox, oy, oz) = ray.origin;
+    let (dx, dy, dz) = ray.direction;
+    match &surface.kind {
+        SurfaceKind::Plane { a, b, c, d } => {
+            let denom = a * dx + b * dy + c * dz;
+            if denom.abs() < EPSILON {
+                vec![]
+            } else {
+                vec![(d - (a * ox + b * oy + c * oz)) / denom]
+            }
+        }
+        SurfaceKind::Sphere { x0, y0, z0, radius } => {
+            let f = [ox - x0, oy - y0, oz - z0];
+            let dir = [dx, dy, dz];
+            let a = dir[0] * dir[0] + dir[1] * dir[1] + dir[2] * dir[2];
+            let b = 2.0 * (f[0] * dir[0] + f[1] * dir[1] + f[2] * dir[2]);
+            let c = f[0] * f[0] + f[1] * f[1] + f[2] * f[2] - radius * radius;
+            quadratic_roots(a, b, c)
+        }
+        SurfaceKind::Cylinder { axis, origin, radius } => {
+            let f = [ox - origin[0], oy - origin[1], oz - origin[2]];
+            let dir = [dx, dy, dz];
+            let f_dot_axis = f[0] * axis[0] + f[1] * axis[1] + f[2] * axis[2];
+            let d_dot_axis = dir[0] * axis[0] + dir[1] * axis[1] + dir[2] * axis[2];
+            let f_perp = [
+                f[0] - f_dot_axis * axis[0],
+                f[1] - f_dot_axis * axis[1],
+                f[2] - f_dot_axis * axis[2],
+            ];
+            let d_perp = [
+                dir[0] - d_dot_axis * axis[0],
+                dir[1] - d_dot_axis * axis[1],
+                dir[2] - d_dot_axis * axis[2],
+            ];
+            let a = d_perp[0] * d_perp[0] + d_perp[1] * d_perp[1] + d_perp[2] * d_perp[2];
+            let b = 2.0 * (f_perp[0] * d_perp[0] + f_perp[1] * d_perp[1] + f_perp[2] * d_perp[2]);
+            let c = f_perp[0] * f_perp[0] + f_perp[1] * f_perp[1] + f_perp[2] * f_perp[2] - radius * radius;
+            quadratic_roots(a, b, c)
+        }
+        SurfaceKind::Triangle { v0, v1, v2 } => {
+            match crate::surface::moller_trumbore([ox, oy, oz], [dx, dy, dz], *v0, *v1, *v2) {
+                Some(t) => vec![t],
+                None => vec![],
+            }
+        }
+        SurfaceKind::Mesh { triangles, bbox } => {
+            if let Some(bbox) = bbox {
+                if !bbox.intersects_ray(ray) {
+                    return vec![];
                 }
-                RegionExpr::Complement(inner) => find_sphere_bounds(inner),
-            }
-        }
-    let sphere_bounds = find_sphere_bounds(&self.expr);
-
-        let lower = [
-            sphere_bounds.map_or(x_bounds.0, |b| x_bounds.0.max(b.0[0])),
-            sphere_bounds.map_or(y_bounds.0, |b| y_bounds.0.max(b.0[1])),
-            sphere_bounds.map_or(z_bounds.0, |b| z_bounds.0.max(b.0[2])),
-        ];
-        let upper = [
-            sphere_bounds.map_or(x_bounds.1, |b| x_bounds.1.min(b.1[0])),
-            sphere_bounds.map_or(y_bounds.1, |b| y_bounds.1.min(b.1[1])),
-            sphere_bounds.map_or(z_bounds.1, |b| z_bounds.1.min(b.1[2])),
-        ];
-
-        // If any min > max, region is empty: return empty bounding box
-        if lower[0] > upper[0] || lower[1] > upper[1] || lower[2] > upper[2] {
-            return crate::bounding_box::BoundingBox::new(
-                [f64::INFINITY; 3],
-                [f64::NEG_INFINITY; 3],
-            );
+            }
+            triangles
+                .iter()
+                .filter_map(|tri| crate::surface::moller_trumbore([ox, oy, oz], [dx, dy, dz], tri[0], tri[1], tri[2]))
+                .collect()
+        }
+        SurfaceKind::Cone { apex, axis, half_angle } => {
+            let w = [ox - apex[0], oy - apex[1], oz - apex[2]];
+            let dir = [dx, dy, dz];
+            let w_axial = w[0] * axis[0] + w[1] * axis[1] + w[2] * axis[2];
+            let d_axial = dir[0] * axis[0] + dir[1] * axis[1] + dir[2] * axis[2];
+            let w_dot_w = w[0] * w[0] + w[1] * w[1] + w[2] * w[2];
+            let w_dot_d = w[0] * dir[0] + w[1] * dir[1] + w[2] * dir[2];
+            let d_dot_d = dir[0] * dir[0] + dir[1] * dir[1] + dir[2] * dir[2];
+            let cos2 = {
+                let cos = crate::ops::sin_cos(*half_angle).1;
+                cos * cos
+            };
+            let a = d_axial * d_axial - cos2 * d_dot_d;
+            let b = 2.0 * (w_axial * d_axial - cos2 * w_dot_d);
+            let c = w_axial * w_axial - cos2 * w_dot_w;
+            quadratic_roots(a, b, c)
+        }
+        SurfaceKind::Quadric { a, b, c, d, e, f, g, h, j, k } => {
+            let coeff_a = a * dx * dx + b * dy * dy + c * dz * dz + d * dx * dy + e * dy * dz + f * dx * dz;
+            let coeff_b = 2.0 * a * ox * dx
+                + 2.0 * b * oy * dy
+                + 2.0 * c * oz * dz
+                + d * (ox * dy + oy * dx)
+                + e * (oy * dz + oz * dy)
+                + f * (ox * dz + oz * dx)
+                + g * dx
+                + h * dy
+                + j * dz;
+            let coeff_c = a * ox * ox
+                + b * oy * oy
+                + c * oz * oz
+                + d * ox * oy
+                + e * oy * oz
+                + f * ox * oz
+                + g * ox
+                + h * oy
+                + j * oz
+                + k;
+            quadratic_roots(coeff_a, coeff_b, coeff_c)
         }
+    }
+}
 
-        crate::bounding_box::BoundingBox::new(lower, upper)
+/// Real roots of `a*t^2 + b*t + c = 0`, handling the degenerate linear case.
+fn quadratic_roots(a: f64, b: f64, c: f64) -> Vec<f64> {
+    if a.abs() < EPSILON {
+        if b.abs() < EPSILON {
+            return vec![];
+        }
+        return vec![-c / b];
+    }
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        vec![]
+    } else if discriminant.abs() < EPSILON {
+        vec![-b / (2.0 * a)]
+    } else {
+        let sqrt_disc = crate::ops::sqrt(discriminant);
+        vec![(-b - sqrt_disc) / (2.0 * a), (-b + sqrt_disc) / (2.0 * a)]
     }
 }
 
@@ -170,6 +709,161 @@ impl RegionExpr {
             RegionExpr::Complement(inner) => !inner.evaluate_contains(point),
         }
     }
+
+    /// Rebuild this expression with every surface repositioned by `t`.
+    pub fn transformed(&self, t: &Transform) -> RegionExpr {
+        match self {
+            RegionExpr::Halfspace(hs) => RegionExpr::Halfspace(match hs {
+                HalfspaceType::Above(surf) => HalfspaceType::Above(Arc::new(surf.transformed(t))),
+                HalfspaceType::Below(surf) => HalfspaceType::Below(Arc::new(surf.transformed(t))),
+            }),
+            RegionExpr::Union(a, b) => {
+                RegionExpr::Union(Box::new(a.transformed(t)), Box::new(b.transformed(t)))
+            }
+            RegionExpr::Intersection(a, b) => {
+                RegionExpr::Intersection(Box::new(a.transformed(t)), Box::new(b.transformed(t)))
+            }
+            RegionExpr::Complement(inner) => RegionExpr::Complement(Box::new(inner.transformed(t))),
+        }
+    }
+
+    /// Conservatively classify `bbox` against this expression, recursing
+    /// through the boolean tree.
+    pub fn classify_box(&self, bbox: &crate::bounding_box::BoundingBox) -> Relation {
+        match self {
+            RegionExpr::Halfspace(hs) => {
+                let (surf, is_above) = match hs {
+                    HalfspaceType::Above(surf) => (surf, true),
+                    HalfspaceType::Below(surf) => (surf, false),
+                };
+                let below_relation = classify_surface_box(surf, bbox);
+                if is_above {
+                    invert_relation(below_relation)
+                } else {
+                    below_relation
+                }
+            }
+            RegionExpr::Intersection(a, b) => {
+                match (a.classify_box(bbox), b.classify_box(bbox)) {
+                    (Relation::Outside, _) | (_, Relation::Outside) => Relation::Outside,
+                    (Relation::Inside, Relation::Inside) => Relation::Inside,
+                    _ => Relation::Crossing,
+                }
+            }
+            RegionExpr::Union(a, b) => match (a.classify_box(bbox), b.classify_box(bbox)) {
+                (Relation::Inside, _) | (_, Relation::Inside) => Relation::Inside,
+                (Relation::Outside, Relation::Outside) => Relation::Outside,
+                _ => Relation::Crossing,
+            },
+            RegionExpr::Complement(inner) => invert_relation(inner.classify_box(bbox)),
+        }
+    }
+
+    /// Render this expression as a CSG string; see `Region::to_expression_string`.
+    pub fn to_expression_string(&self) -> String {
+        match self {
+            RegionExpr::Halfspace(HalfspaceType::Above(surf)) => format!("+{}", surf.surface_id),
+            RegionExpr::Halfspace(HalfspaceType::Below(surf)) => format!("-{}", surf.surface_id),
+            RegionExpr::Complement(inner) => {
+                let rendered = inner.to_expression_string();
+                if precedence(inner) < precedence(self) {
+                    format!("~({rendered})")
+                } else {
+                    format!("~{rendered}")
+                }
+            }
+            RegionExpr::Intersection(a, b) => {
+                format!("{} & {}", wrap_if_lower(a, precedence(self)), wrap_if_lower(b, precedence(self)))
+            }
+            RegionExpr::Union(a, b) => {
+                format!("{} | {}", wrap_if_lower(a, precedence(self)), wrap_if_lower(b, precedence(self)))
+            }
+        }
+    }
+}
+
+fn wrap_if_lower(expr: &RegionExpr, min_prec: u8) -> String {
+    let rendered = expr.to_expression_string();
+    if precedence(expr) < min_prec {
+        format!("({rendered})")
+    } else {
+        rendered
+    }
+}
+
+fn invert_relation(r: Relation) -> Relation {
+    match r {
+        Relation::Inside => Relation::Outside,
+        Relation::Outside => Relation::Inside,
+        Relation::Crossing => Relation::Crossing,
+    }
+}
+
+/// Classify `bbox` against a single surface's "below" (negative) halfspace;
+/// callers invert the result for the "above" halfspace. A surface with an
+/// `inverse_transform` always gives `Crossing`.
+fn classify_surface_box(surf: &Surface, bbox: &crate::bounding_box::BoundingBox) -> Relation {
+    if surf.inverse_transform.is_some() {
+        return Relation::Crossing;
+    }
+    let lo = bbox.lower_left_corner;
+    let hi = bbox.upper_right_corner;
+    match &surf.kind {
+        SurfaceKind::Plane { a, b, c, d } => {
+            // The plane equation is evaluated directly at every corner, so
+            // this needs no axis-alignment assumption: it's exact for any
+            // orientation, including negative-coefficient and tilted planes.
+            let corner_value = |x: f64, y: f64, z: f64| a * x + b * y + c * z - d;
+            let mut min_value = f64::INFINITY;
+            let mut max_value = f64::NEG_INFINITY;
+            for &x in &[lo[0], hi[0]] {
+                for &y in &[lo[1], hi[1]] {
+                    for &z in &[lo[2], hi[2]] {
+                        let v = corner_value(x, y, z);
+                        min_value = min_value.min(v);
+                        max_value = max_value.max(v);
+                    }
+                }
+            }
+            if max_value < 0.0 {
+                Relation::Inside
+            } else if min_value > 0.0 {
+                Relation::Outside
+            } else {
+                Relation::Crossing
+            }
+        }
+        SurfaceKind::Sphere { x0, y0, z0, radius } => {
+            let center = [*x0, *y0, *z0];
+            let mut nearest_sq = 0.0;
+            let mut farthest_sq = 0.0;
+            for axis in 0..3 {
+                let lo_d = lo[axis] - center[axis];
+                let hi_d = hi[axis] - center[axis];
+                let nearest = if center[axis] < lo[axis] {
+                    lo_d
+                } else if center[axis] > hi[axis] {
+                    hi_d
+                } else {
+                    0.0
+                };
+                nearest_sq += nearest * nearest;
+                farthest_sq += crate::ops::powi(lo_d.abs().max(hi_d.abs()), 2);
+            }
+            if farthest_sq <= radius * radius {
+                Relation::Inside
+            } else if nearest_sq >= radius * radius {
+                Relation::Outside
+            } else {
+                Relation::Crossing
+            }
+        }
+        SurfaceKind::Cylinder { .. }
+        | SurfaceKind::Triangle { .. }
+        | SurfaceKind::Mesh { .. }
+        | SurfaceKind::Cone { .. }
+        | SurfaceKind::Quadric { .. } => Relation::Crossing,
+    }
 }
 
 #[cfg(test)]
@@ -181,8 +875,8 @@ mod tests {
     #[test]
     fn test_region_contains() {
         // Create two surfaces
-        let s1 = Surface { surface_id: 1, kind: SurfaceKind::Plane { a: 0.0, b: 0.0, c: 1.0, d: -5.0 }, boundary_type: crate::surface::BoundaryType::default() };
-        let s2 = Surface { surface_id: 2, kind: SurfaceKind::Sphere { x0: 0.0, y0: 0.0, z0: 0.0, radius: 3.0 }, boundary_type: crate::surface::BoundaryType::default() };
+        let s1 = Surface { surface_id: 1, kind: SurfaceKind::Plane { a: 0.0, b: 0.0, c: 1.0, d: -5.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let s2 = Surface { surface_id: 2, kind: SurfaceKind::Sphere { x0: 0.0, y0: 0.0, z0: 0.0, radius: 3.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
 
         // Map of surfaces by surface_id
         let mut surfaces = HashMap::new();
@@ -205,7 +899,7 @@ mod tests {
     #[test]
     fn test_sphere_bounding_box() {
         // Sphere of radius 2 at (0,0,0)
-        let s = Surface { surface_id: 1, kind: SurfaceKind::Sphere { x0: 0.0, y0: 0.0, z0: 0.0, radius: 2.0 }, boundary_type: crate::surface::BoundaryType::default() };
+        let s = Surface { surface_id: 1, kind: SurfaceKind::Sphere { x0: 0.0, y0: 0.0, z0: 0.0, radius: 2.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
         let mut surfaces = HashMap::new();
         surfaces.insert(s.surface_id, s.clone());
         let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(s.clone())));
@@ -217,9 +911,9 @@ mod tests {
     #[test]
     fn test_box_and_sphere_bounding_box() {
         // XPlanes at x=2.1 and x=-2.1, sphere at origin with radius 4.2
-        let s1 = Surface { surface_id: 1, kind: SurfaceKind::Plane { a: 1.0, b: 0.0, c: 0.0, d: 2.1 }, boundary_type: crate::surface::BoundaryType::default() };
-        let s2 = Surface { surface_id: 2, kind: SurfaceKind::Plane { a: 1.0, b: 0.0, c: 0.0, d: -2.1 }, boundary_type: crate::surface::BoundaryType::default() };
-        let s3 = Surface { surface_id: 3, kind: SurfaceKind::Sphere { x0: 0.0, y0: 0.0, z0: 0.0, radius: 4.2 }, boundary_type: crate::surface::BoundaryType::default() };
+        let s1 = Surface { surface_id: 1, kind: SurfaceKind::Plane { a: 1.0, b: 0.0, c: 0.0, d: 2.1 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let s2 = Surface { surface_id: 2, kind: SurfaceKind::Plane { a: 1.0, b: 0.0, c: 0.0, d: -2.1 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let s3 = Surface { surface_id: 3, kind: SurfaceKind::Sphere { x0: 0.0, y0: 0.0, z0: 0.0, radius: 4.2 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
         let mut surfaces = HashMap::new();
         surfaces.insert(s1.surface_id, s1.clone());
         surfaces.insert(s2.surface_id, s2.clone());
@@ -236,7 +930,7 @@ mod tests {
     #[test]
     fn test_zplane_bounding_box() {
         // ZPlane at z=3.5
-        let s = Surface { surface_id: 1, kind: SurfaceKind::Plane { a: 0.0, b: 0.0, c: 1.0, d: 3.5 }, boundary_type: crate::surface::BoundaryType::default() };
+        let s = Surface { surface_id: 1, kind: SurfaceKind::Plane { a: 0.0, b: 0.0, c: 1.0, d: 3.5 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
         let mut surfaces = HashMap::new();
         surfaces.insert(s.surface_id, s.clone());
         // Region: z < 3.5 (Below ZPlane)
@@ -253,7 +947,7 @@ mod tests {
     #[test]
     fn test_xplane_bounding_box() {
         // XPlane at x=1.5
-        let s = Surface { surface_id: 1, kind: SurfaceKind::Plane { a: 1.0, b: 0.0, c: 0.0, d: 1.5 }, boundary_type: crate::surface::BoundaryType::default() };
+        let s = Surface { surface_id: 1, kind: SurfaceKind::Plane { a: 1.0, b: 0.0, c: 0.0, d: 1.5 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
         let mut surfaces = HashMap::new();
         surfaces.insert(s.surface_id, s.clone());
         // Region: x < 1.5 (Below XPlane)
@@ -267,10 +961,247 @@ mod tests {
         assert_eq!(bbox.upper_right[2], f64::INFINITY);
     }
 
+    #[test]
+    fn test_distance_to_boundary_sphere() {
+        // Sphere of radius 2 at the origin, ray starting outside heading in.
+        let s = Surface { surface_id: 1, kind: SurfaceKind::Sphere { x0: 0.0, y0: 0.0, z0: 0.0, radius: 2.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(s)));
+        let ray = Ray { origin: (-5.0, 0.0, 0.0), direction: (1.0, 0.0, 0.0) };
+        let (t, surface) = region.distance_to_boundary(&ray).unwrap();
+        assert!((t - 3.0).abs() < 1e-9);
+        assert_eq!(surface.surface_id, 1);
+    }
+
+    #[test]
+    fn test_distance_to_boundary_plane() {
+        // Region below the plane x = 1.5, ray heading toward it from the origin.
+        let s = Surface { surface_id: 1, kind: SurfaceKind::Plane { a: 1.0, b: 0.0, c: 0.0, d: 1.5 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(s)));
+        let ray = Ray { origin: (0.0, 0.0, 0.0), direction: (1.0, 0.0, 0.0) };
+        let (t, surface) = region.distance_to_boundary(&ray).unwrap();
+        assert!((t - 1.5).abs() < 1e-9);
+        assert_eq!(surface.surface_id, 1);
+    }
+
+    #[test]
+    fn test_distance_to_boundary_miss() {
+        // Sphere off to the side; ray travels parallel and never crosses it.
+        let s = Surface { surface_id: 1, kind: SurfaceKind::Sphere { x0: 10.0, y0: 10.0, z0: 0.0, radius: 1.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(s)));
+        let ray = Ray { origin: (0.0, 0.0, 0.0), direction: (1.0, 0.0, 0.0) };
+        assert!(region.distance_to_boundary(&ray).is_none());
+    }
+
+    #[test]
+    fn test_distance_to_boundary_triangle() {
+        // Triangle in the z=0 plane, ray heading straight up through it.
+        let s = Surface::new_triangle(
+            [0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            1,
+            None,
+        );
+        let ray = Ray { origin: (0.2, 0.2, -3.0), direction: (0.0, 0.0, 1.0) };
+        let hits = surface_intersections(&s, &ray);
+        assert_eq!(hits.len(), 1);
+        assert!((hits[0] - 3.0).abs() < 1e-9);
+
+        // A ray that misses the triangle's footprint should not register a hit.
+        let miss_ray = Ray { origin: (5.0, 5.0, -3.0), direction: (0.0, 0.0, 1.0) };
+        assert!(surface_intersections(&s, &miss_ray).is_empty());
+    }
+
+    #[test]
+    fn test_distance_to_boundary_cone() {
+        // Cone with apex at origin opening along +z, half-angle 45 degrees.
+        let s = Surface::new_cone(
+            [0.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0],
+            std::f64::consts::FRAC_PI_4,
+            1,
+            None,
+        );
+        // A ray parallel to the z axis at radius 1 crosses the cone's implicit
+        // surface twice: once on the +z nappe (z = 1) and once on the mirrored
+        // -z nappe (z = -1), since the cone equation is quadratic in the axial
+        // coordinate and doesn't distinguish the two nappes.
+        let ray = Ray { origin: (1.0, 0.0, -3.0), direction: (0.0, 0.0, 1.0) };
+        let mut hits = surface_intersections(&s, &ray);
+        hits.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(hits.len(), 2);
+        assert!((hits[0] - 2.0).abs() < 1e-9);
+        assert!((hits[1] - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_transformed_sphere_moves_with_translation() {
+        let s = Surface { surface_id: 1, kind: SurfaceKind::Sphere { x0: 0.0, y0: 0.0, z0: 0.0, radius: 1.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(s)));
+
+        let moved = region.transformed(&crate::transform::Transform::translation([5.0, 0.0, 0.0]));
+        assert!(!moved.contains((0.0, 0.0, 0.0)));
+        assert!(moved.contains((5.0, 0.0, 0.0)));
+        assert!(!region.contains((5.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_region_translate_matches_transformed() {
+        let s = Surface { surface_id: 1, kind: SurfaceKind::Sphere { x0: 0.0, y0: 0.0, z0: 0.0, radius: 1.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(s)));
+
+        let moved = region.translate([5.0, 0.0, 0.0]);
+        assert!(!moved.contains((0.0, 0.0, 0.0)));
+        assert!(moved.contains((5.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_transformed_bounding_box_tracks_translation() {
+        let s = Surface { surface_id: 1, kind: SurfaceKind::Sphere { x0: 0.0, y0: 0.0, z0: 0.0, radius: 2.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(s)));
+
+        let moved = region.transformed(&crate::transform::Transform::translation([3.0, 0.0, 0.0]));
+        let bbox = moved.bounding_box();
+        assert_eq!(bbox.lower_left_corner, [1.0, -2.0, -2.0]);
+        assert_eq!(bbox.upper_right_corner, [5.0, 2.0, 2.0]);
+    }
+
+    #[test]
+    fn test_bounding_box_mixes_transformed_and_untransformed_surfaces() {
+        // A sphere translated far from the origin, intersected with a fixed
+        // (untransformed) plane: "x > -200" should still contain the moved
+        // sphere, not collapse to the sphere's pre-translation local box.
+        let sphere = Surface { surface_id: 1, kind: SurfaceKind::Sphere { x0: 0.0, y0: 0.0, z0: 0.0, radius: 10.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None }
+            .translate([-100.0, 0.0, 0.0]);
+        let plane = Surface { surface_id: 2, kind: SurfaceKind::Plane { a: 1.0, b: 0.0, c: 0.0, d: -200.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+
+        let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(sphere)))
+            .intersection(&Region::new_from_halfspace(HalfspaceType::Above(Arc::new(plane))));
+
+        assert!(region.contains((-100.0, 0.0, 0.0)));
+
+        let bbox = region.bounding_box();
+        assert!(bbox.lower_left_corner[0] <= -100.0 && bbox.upper_right_corner[0] >= -100.0);
+        assert_eq!(bbox.lower_left_corner, [-110.0, -10.0, -10.0]);
+        assert_eq!(bbox.upper_right_corner, [-90.0, 10.0, 10.0]);
+    }
+
+    #[test]
+    fn test_classify_box_sphere() {
+        let s = Surface { surface_id: 1, kind: SurfaceKind::Sphere { x0: 0.0, y0: 0.0, z0: 0.0, radius: 5.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(s)));
+
+        let inside = crate::bounding_box::BoundingBox::new([-1.0, -1.0, -1.0], [1.0, 1.0, 1.0]);
+        assert_eq!(region.classify_box(&inside), Relation::Inside);
+
+        let outside = crate::bounding_box::BoundingBox::new([10.0, 10.0, 10.0], [12.0, 12.0, 12.0]);
+        assert_eq!(region.classify_box(&outside), Relation::Outside);
+
+        let crossing = crate::bounding_box::BoundingBox::new([4.0, -1.0, -1.0], [6.0, 1.0, 1.0]);
+        assert_eq!(region.classify_box(&crossing), Relation::Crossing);
+    }
+
+    #[test]
+    fn test_classify_box_intersection_and_complement() {
+        let s1 = Surface { surface_id: 1, kind: SurfaceKind::Plane { a: 1.0, b: 0.0, c: 0.0, d: 0.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(s1)));
+
+        // Entirely on the "below" side of x = 0.
+        let inside = crate::bounding_box::BoundingBox::new([-5.0, -1.0, -1.0], [-1.0, 1.0, 1.0]);
+        assert_eq!(region.classify_box(&inside), Relation::Inside);
+        assert_eq!(region.complement().classify_box(&inside), Relation::Outside);
+
+        // Straddles x = 0.
+        let crossing = crate::bounding_box::BoundingBox::new([-1.0, -1.0, -1.0], [1.0, 1.0, 1.0]);
+        assert_eq!(region.classify_box(&crossing), Relation::Crossing);
+    }
+
+    #[test]
+    fn test_classify_box_negative_coefficient_and_tilted_plane() {
+        // "x > 0" written with a negative coefficient (-x < 0) instead of the
+        // positive-unit form; must classify as precisely as the positive case.
+        let negated = Surface { surface_id: 1, kind: SurfaceKind::Plane { a: -1.0, b: 0.0, c: 0.0, d: 0.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(negated)));
+
+        let inside = crate::bounding_box::BoundingBox::new([1.0, -1.0, -1.0], [5.0, 1.0, 1.0]);
+        assert_eq!(region.classify_box(&inside), Relation::Inside);
+
+        let outside = crate::bounding_box::BoundingBox::new([-5.0, -1.0, -1.0], [-1.0, 1.0, 1.0]);
+        assert_eq!(region.classify_box(&outside), Relation::Outside);
+
+        // A tilted plane (x + y < 0) is likewise classified exactly rather
+        // than degrading to `Crossing` just because it isn't axis-aligned.
+        let tilted = Surface { surface_id: 2, kind: SurfaceKind::Plane { a: 1.0, b: 1.0, c: 0.0, d: 0.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let tilted_region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(tilted)));
+
+        let tilted_inside = crate::bounding_box::BoundingBox::new([-5.0, -5.0, -1.0], [-3.0, -3.0, 1.0]);
+        assert_eq!(tilted_region.classify_box(&tilted_inside), Relation::Inside);
+
+        let tilted_outside = crate::bounding_box::BoundingBox::new([3.0, 3.0, -1.0], [5.0, 5.0, 1.0]);
+        assert_eq!(tilted_region.classify_box(&tilted_outside), Relation::Outside);
+    }
+
+    #[test]
+    fn test_region_difference_subtracts_inner_sphere() {
+        // A sphere of radius 2 with a radius-1 sphere carved out of its center.
+        let outer = Surface { surface_id: 1, kind: SurfaceKind::Sphere { x0: 0.0, y0: 0.0, z0: 0.0, radius: 2.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let inner = Surface { surface_id: 2, kind: SurfaceKind::Sphere { x0: 0.0, y0: 0.0, z0: 0.0, radius: 1.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let shell = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(outer)))
+            .difference(&Region::new_from_halfspace(HalfspaceType::Below(Arc::new(inner))));
+
+        assert!(!shell.contains((0.0, 0.0, 0.0))); // carved-out void
+        assert!(shell.contains((1.5, 0.0, 0.0))); // in the shell
+        assert!(!shell.contains((3.0, 0.0, 0.0))); // outside the outer sphere
+    }
+
+    #[test]
+    fn test_bounding_sphere_exact_for_single_sphere() {
+        let s = Surface { surface_id: 1, kind: SurfaceKind::Sphere { x0: 1.0, y0: 2.0, z0: 3.0, radius: 4.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(s)));
+        let sphere = region.bounding_sphere();
+        assert_eq!(sphere.center, [1.0, 2.0, 3.0]);
+        assert_eq!(sphere.radius, 4.0);
+    }
+
+    #[test]
+    fn test_bounding_sphere_circumscribes_box_region() {
+        // Cube from (-1,-1,-1) to (1,1,1): circumscribing sphere has radius sqrt(3).
+        let sx0 = Surface { surface_id: 1, kind: SurfaceKind::Plane { a: 1.0, b: 0.0, c: 0.0, d: -1.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let sx1 = Surface { surface_id: 2, kind: SurfaceKind::Plane { a: 1.0, b: 0.0, c: 0.0, d: 1.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let sy0 = Surface { surface_id: 3, kind: SurfaceKind::Plane { a: 0.0, b: 1.0, c: 0.0, d: -1.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let sy1 = Surface { surface_id: 4, kind: SurfaceKind::Plane { a: 0.0, b: 1.0, c: 0.0, d: 1.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let sz0 = Surface { surface_id: 5, kind: SurfaceKind::Plane { a: 0.0, b: 0.0, c: 1.0, d: -1.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let sz1 = Surface { surface_id: 6, kind: SurfaceKind::Plane { a: 0.0, b: 0.0, c: 1.0, d: 1.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let cube = Region::new_from_halfspace(HalfspaceType::Above(Arc::new(sx0)))
+            .intersection(&Region::new_from_halfspace(HalfspaceType::Below(Arc::new(sx1))))
+            .intersection(&Region::new_from_halfspace(HalfspaceType::Above(Arc::new(sy0))))
+            .intersection(&Region::new_from_halfspace(HalfspaceType::Below(Arc::new(sy1))))
+            .intersection(&Region::new_from_halfspace(HalfspaceType::Above(Arc::new(sz0))))
+            .intersection(&Region::new_from_halfspace(HalfspaceType::Below(Arc::new(sz1))));
+        let sphere = cube.bounding_sphere();
+        assert_eq!(sphere.center, [0.0, 0.0, 0.0]);
+        assert!((sphere.radius - 3.0_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bounding_sphere_union_is_tighter_than_aabb_circumscription() {
+        // Two far-apart unit spheres: their union's AABB is a long box whose
+        // circumscribing sphere is much looser than combining the two exact
+        // per-branch spheres directly.
+        let a = Surface { surface_id: 1, kind: SurfaceKind::Sphere { x0: 0.0, y0: 0.0, z0: 0.0, radius: 1.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let b = Surface { surface_id: 2, kind: SurfaceKind::Sphere { x0: 20.0, y0: 0.0, z0: 0.0, radius: 1.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
+        let region = Region::new_from_halfspace(HalfspaceType::Below(Arc::new(a)))
+            .union(&Region::new_from_halfspace(HalfspaceType::Below(Arc::new(b))));
+
+        let sphere = region.bounding_sphere();
+        assert!((sphere.center[0] - 10.0).abs() < 1e-9);
+        assert!((sphere.radius - 11.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_yplane_bounding_box() {
         // YPlane at y=-2.0
-        let s = Surface { surface_id: 1, kind: SurfaceKind::Plane { a: 0.0, b: 1.0, c: 0.0, d: -2.0 }, boundary_type: crate::surface::BoundaryType::default() };
+        let s = Surface { surface_id: 1, kind: SurfaceKind::Plane { a: 0.0, b: 1.0, c: 0.0, d: -2.0 }, boundary_type: crate::surface::BoundaryType::default(), inverse_transform: None };
         let mut surfaces = HashMap::new();
         surfaces.insert(s.surface_id, s.clone());
         // Region: y > -2.0 (Above YPlane)
@@ -283,4 +1214,65 @@ mod tests {
         assert_eq!(bbox.lower_left[2], f64::NEG_INFINITY);
         assert_eq!(bbox.upper_right[2], f64::INFINITY);
     }
+
+    fn id_surfaces(ids: &[usize]) -> HashMap<usize, Arc<Surface>> {
+        ids.iter()
+            .map(|&id| {
+                let surface = Surface {
+                    surface_id: id,
+                    kind: SurfaceKind::Sphere { x0: id as f64, y0: 0.0, z0: 0.0, radius: 1.0 },
+                    boundary_type: crate::surface::BoundaryType::default(),
+                    inverse_transform: None,
+                };
+                (id, Arc::new(surface))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_expression_round_trip_intersection_chain() {
+        let surfaces = id_surfaces(&[1, 2, 3]);
+        let region = Region::new_from_halfspace(HalfspaceType::Below(surfaces[&1].clone()))
+            .intersection(&Region::new_from_halfspace(HalfspaceType::Above(surfaces[&2].clone())))
+            .intersection(&Region::new_from_halfspace(HalfspaceType::Below(surfaces[&3].clone())));
+
+        let text = region.to_expression_string();
+        assert_eq!(text, "-1 & +2 & -3");
+
+        let parsed = Region::from_expression_string(&text, &surfaces).unwrap();
+        assert_eq!(parsed.to_expression_string(), text);
+    }
+
+    #[test]
+    fn test_expression_round_trip_union_and_complement() {
+        let surfaces = id_surfaces(&[1, 2, 3]);
+        let region = Region::new_from_halfspace(HalfspaceType::Below(surfaces[&1].clone()))
+            .union(&Region::new_from_halfspace(HalfspaceType::Above(surfaces[&2].clone())))
+            .complement()
+            .intersection(&Region::new_from_halfspace(HalfspaceType::Below(surfaces[&3].clone())));
+
+        let text = region.to_expression_string();
+        assert_eq!(text, "~(-1 | +2) & -3");
+
+        let parsed = Region::from_expression_string(&text, &surfaces).unwrap();
+        assert!((0..3).all(|i| {
+            let p = (i as f64, 0.0, 0.0);
+            parsed.contains(p) == region.contains(p)
+        }));
+    }
+
+    #[test]
+    fn test_expression_parse_unknown_surface_id() {
+        let surfaces = id_surfaces(&[1]);
+        let err = Region::from_expression_string("-1 & +99", &surfaces).unwrap_err();
+        assert_eq!(err, ExpressionError::UnknownSurfaceId(99));
+    }
+
+    #[test]
+    fn test_expression_parse_malformed() {
+        let surfaces = id_surfaces(&[1]);
+        assert!(Region::from_expression_string("-1 &", &surfaces).is_err());
+        assert!(Region::from_expression_string("(-1", &surfaces).is_err());
+        assert!(Region::from_expression_string("-1 -2", &surfaces).is_err());
+    }
 }
@@ -1,5 +1,6 @@
 use pyo3::prelude::*;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use crate::region::{Region, RegionExpr, HalfspaceType};
 use crate::surface::Surface;
@@ -35,6 +36,54 @@ impl PyRegion {
         self.expr.bounding_box()
     }
 
+    /// Distance along a ray to the nearest crossing of this region's boundary,
+    /// and the `surface_id` of the surface that was hit.
+    pub fn distance_to_boundary(&self, origin: (f64, f64, f64), direction: (f64, f64, f64)) -> Option<(f64, usize)> {
+        Python::with_gil(|py| {
+            let region = self.expr.to_region(py);
+            let ray = crate::region::Ray { origin, direction };
+            region.distance_to_boundary(&ray).map(|(t, surface)| (t, surface.surface_id))
+        })
+    }
+
+    /// Rotate every surface in this region by `matrix`, e.g. to orient a unit
+    /// cell before instancing it in a lattice.
+    pub fn rotate(&self, matrix: [[f64; 3]; 3]) -> PyRegion {
+        Python::with_gil(|py| PyRegion {
+            expr: self.expr.transformed(py, &crate::transform::Transform::rotation(matrix)),
+        })
+    }
+
+    /// Translate every surface in this region by `offset`, e.g. to instance a
+    /// unit cell at a different lattice position.
+    pub fn translate(&self, offset: [f64; 3]) -> PyRegion {
+        Python::with_gil(|py| PyRegion {
+            expr: self.expr.transformed(py, &crate::transform::Transform::translation(offset)),
+        })
+    }
+
+    /// Render this region as a compact CSG expression string, e.g. `-1 & +2`.
+    pub fn to_expression_string(&self) -> String {
+        Python::with_gil(|py| self.expr.to_region(py).to_expression_string())
+    }
+
+    /// Parse a CSG expression produced by `to_expression_string`, resolving
+    /// surface references against `surfaces` (keyed by `surface_id`).
+    #[staticmethod]
+    pub fn from_expression_string(expr: &str, surfaces: HashMap<usize, Py<PySurface>>) -> PyResult<PyRegion> {
+        Python::with_gil(|py| {
+            let native_surfaces: HashMap<usize, Arc<Surface>> = surfaces
+                .iter()
+                .map(|(&id, s)| (id, Arc::new(s.as_ref(py).borrow().inner.clone())))
+                .collect();
+            let region = crate::region::Region::from_expression_string(expr, &native_surfaces)
+                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+            Ok(PyRegion {
+                expr: PyRegionExpr::from_native_expr(&region.expr, &surfaces),
+            })
+        })
+    }
+
     fn __and__(&self, other: &PyAny) -> PyResult<PyRegion> {
         if let Ok(other_region) = other.extract::<PyRef<PyRegion>>() {
             Ok(PyRegion {
@@ -62,6 +111,29 @@ impl PyRegion {
             Err(pyo3::exceptions::PyTypeError::new_err("Operand must be PyRegion or PyHalfspace"))
         }
     }
+
+    /// `self - other`, i.e. `self` with `other` subtracted out: desugars to
+    /// `self & ~other`.
+    fn __sub__(&self, other: &PyAny) -> PyResult<PyRegion> {
+        self.difference(other)
+    }
+
+    /// Subtract `other` from this region, i.e. `self ∩ ~other`.
+    fn difference(&self, other: &PyAny) -> PyResult<PyRegion> {
+        let other_expr = if let Ok(other_region) = other.extract::<PyRef<PyRegion>>() {
+            other_region.expr.clone()
+        } else if let Ok(other_halfspace) = other.extract::<PyRef<PyHalfspace>>() {
+            PyRegionExpr::Halfspace(other_halfspace.clone())
+        } else {
+            return Err(pyo3::exceptions::PyTypeError::new_err("Operand must be PyRegion or PyHalfspace"));
+        };
+        Ok(PyRegion {
+            expr: PyRegionExpr::Intersection(
+                Box::new(self.expr.clone()),
+                Box::new(PyRegionExpr::Complement(Box::new(other_expr))),
+            ),
+        })
+    }
 }
 
 #[pyclass]
@@ -125,53 +197,37 @@ impl PyHalfspace {
             }
         })
     }
+    /// Distance along a ray to the nearest crossing of this halfspace's
+    /// boundary, and the `surface_id` of the surface that was hit.
+    pub fn distance_to_boundary(&self, origin: (f64, f64, f64), direction: (f64, f64, f64)) -> Option<(f64, usize)> {
+        Python::with_gil(|py| {
+            let region = PyRegionExpr::Halfspace(self.clone()).to_region(py);
+            let ray = crate::region::Ray { origin, direction };
+            region.distance_to_boundary(&ray).map(|(t, surface)| (t, surface.surface_id))
+        })
+    }
+
     pub fn bounding_box(&self) -> PyBoundingBox {
         Python::with_gil(|py| {
             let surface = self.surface.as_ref(py);
-            match &surface.borrow().inner.kind {
-                crate::surface::SurfaceKind::Plane { a, b, c, d } => {
-                    let mut lower = [f64::NEG_INFINITY; 3];
-                    let mut upper = [f64::INFINITY; 3];
-                    if *a == 1.0 && *b == 0.0 && *c == 0.0 {
-                        if self.is_above {
-                            lower[0] = *d;
-                        } else {
-                            upper[0] = *d;
-                        }
-                    } else if *a == 0.0 && *b == 1.0 && *c == 0.0 {
-                        if self.is_above {
-                            lower[1] = *d;
-                        } else {
-                            upper[1] = *d;
-                        }
-                    } else if *a == 0.0 && *b == 0.0 && *c == 1.0 {
-                        if self.is_above {
-                            lower[2] = *d;
-                        } else {
-                            upper[2] = *d;
-                        }
-                    }
-                    PyBoundingBox {
-                        lower_left: lower,
-                        upper_right: upper,
-                        center: [0.0, 0.0, 0.0],
-                        width: [0.0, 0.0, 0.0],
-                    }
-                }
-                crate::surface::SurfaceKind::Sphere { x0, y0, z0, radius } => {
-                    PyBoundingBox {
-                        lower_left: [*x0 - *radius, *y0 - *radius, *z0 - *radius],
-                        upper_right: [*x0 + *radius, *y0 + *radius, *z0 + *radius],
-                        center: [*x0, *y0, *z0],
-                        width: [2.0 * *radius, 2.0 * *radius, 2.0 * *radius],
-                    }
-                }
-                _ => PyBoundingBox {
-                    lower_left: [f64::NEG_INFINITY; 3],
-                    upper_right: [f64::INFINITY; 3],
-                    center: [0.0, 0.0, 0.0],
-                    width: [0.0, 0.0, 0.0],
-                },
+            // `Surface::bounding_box` does the axis-alignment detection for
+            // every surface kind here; `inside`
+            // is `true` for the "below" (negative) halfspace, so it's the
+            // negation of `is_above`.
+            let (lower, upper) = surface
+                .borrow()
+                .inner
+                .bounding_box(!self.is_above)
+                .unwrap_or(([f64::NEG_INFINITY; 3], [f64::INFINITY; 3]));
+            PyBoundingBox {
+                lower_left: lower,
+                upper_right: upper,
+                center: [
+                    (lower[0] + upper[0]) / 2.0,
+                    (lower[1] + upper[1]) / 2.0,
+                    (lower[2] + upper[2]) / 2.0,
+                ],
+                width: [upper[0] - lower[0], upper[1] - lower[1], upper[2] - lower[2]],
             }
         })
     }
@@ -204,6 +260,87 @@ impl PyHalfspace {
 }
 
 impl PyRegionExpr {
+    /// Build the equivalent native `Region`, cloning each referenced
+    /// `Surface` out of its `Py<PySurface>` handle. Used to delegate ray
+    /// queries (which need the core intersection math) to `Region` instead
+    /// of re-deriving it here.
+    fn to_region(&self, py: Python) -> Region {
+        Region { expr: self.to_native_expr(py) }
+    }
+
+    fn to_native_expr(&self, py: Python) -> RegionExpr {
+        match self {
+            PyRegionExpr::Halfspace(hs) => {
+                let surface = hs.surface.as_ref(py).borrow().inner.clone();
+                RegionExpr::Halfspace(if hs.is_above {
+                    HalfspaceType::Above(Arc::new(surface))
+                } else {
+                    HalfspaceType::Below(Arc::new(surface))
+                })
+            }
+            PyRegionExpr::Union(a, b) => {
+                RegionExpr::Union(Box::new(a.to_native_expr(py)), Box::new(b.to_native_expr(py)))
+            }
+            PyRegionExpr::Intersection(a, b) => {
+                RegionExpr::Intersection(Box::new(a.to_native_expr(py)), Box::new(b.to_native_expr(py)))
+            }
+            PyRegionExpr::Complement(inner) => RegionExpr::Complement(Box::new(inner.to_native_expr(py))),
+        }
+    }
+
+    /// Inverse of `to_native_expr`: rebuild a `PyRegionExpr` from a native
+    /// `RegionExpr`, looking up each referenced surface's `Py<PySurface>`
+    /// handle by `surface_id` in `surfaces` (the same map passed to
+    /// `Region::from_expression_string`, so every id is guaranteed present).
+    fn from_native_expr(expr: &RegionExpr, surfaces: &HashMap<usize, Py<PySurface>>) -> PyRegionExpr {
+        match expr {
+            RegionExpr::Halfspace(HalfspaceType::Above(surf)) => PyRegionExpr::Halfspace(PyHalfspace {
+                surface: surfaces[&surf.surface_id].clone(),
+                is_above: true,
+            }),
+            RegionExpr::Halfspace(HalfspaceType::Below(surf)) => PyRegionExpr::Halfspace(PyHalfspace {
+                surface: surfaces[&surf.surface_id].clone(),
+                is_above: false,
+            }),
+            RegionExpr::Union(a, b) => PyRegionExpr::Union(
+                Box::new(Self::from_native_expr(a, surfaces)),
+                Box::new(Self::from_native_expr(b, surfaces)),
+            ),
+            RegionExpr::Intersection(a, b) => PyRegionExpr::Intersection(
+                Box::new(Self::from_native_expr(a, surfaces)),
+                Box::new(Self::from_native_expr(b, surfaces)),
+            ),
+            RegionExpr::Complement(inner) => {
+                PyRegionExpr::Complement(Box::new(Self::from_native_expr(inner, surfaces)))
+            }
+        }
+    }
+
+    /// Rebuild this tree with every referenced surface repositioned by `t`,
+    /// each wrapped in a fresh `Py<PySurface>` handle.
+    fn transformed(&self, py: Python, t: &crate::transform::Transform) -> PyRegionExpr {
+        match self {
+            PyRegionExpr::Halfspace(hs) => {
+                let transformed = hs.surface.as_ref(py).borrow().inner.transformed(t);
+                PyRegionExpr::Halfspace(PyHalfspace {
+                    surface: Py::new(py, PySurface { inner: transformed }).unwrap(),
+                    is_above: hs.is_above,
+                })
+            }
+            PyRegionExpr::Union(a, b) => PyRegionExpr::Union(
+                Box::new(a.transformed(py, t)),
+                Box::new(b.transformed(py, t)),
+            ),
+            PyRegionExpr::Intersection(a, b) => PyRegionExpr::Intersection(
+                Box::new(a.transformed(py, t)),
+                Box::new(b.transformed(py, t)),
+            ),
+            PyRegionExpr::Complement(inner) => {
+                PyRegionExpr::Complement(Box::new(inner.transformed(py, t)))
+            }
+        }
+    }
+
     pub fn evaluate_contains(&self, point: (f64, f64, f64)) -> bool {
         match self {
             PyRegionExpr::Halfspace(hs) => {
@@ -1,10 +1,31 @@
 use crate::region::{RegionExpr, HalfspaceType};
+use crate::transform::Transform;
 use std::sync::Arc;
 
+/// Error returned by `BoundaryType::from_str_option` callers for an
+/// unrecognized boundary type string.
+pub(crate) const BOUNDARY_TYPE_ERROR: &str =
+    "boundary_type must be 'transmission', 'vacuum', 'reflective', 'periodic', or 'white'";
+
+/// The default albedo for a reflective/white boundary with none specified:
+/// full reflection, no partial absorption at the boundary.
+const DEFAULT_ALBEDO: f64 = 1.0;
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum BoundaryType {
     Transmission,
     Vacuum,
+    /// Specularly reflects a particle back into the cell it came from.
+    /// `albedo` (0.0-1.0) is the fraction of particles reflected rather than
+    /// absorbed at the boundary.
+    Reflective { albedo: f64 },
+    /// Maps a particle crossing this surface to the corresponding point on
+    /// its periodic partner surface instead of reflecting or terminating it.
+    Periodic,
+    /// Reflects a particle back into the cell with a cosine-weighted random
+    /// direction rather than a specular bounce. `albedo` behaves as in
+    /// `Reflective`.
+    White { albedo: f64 },
 }
 
 impl Default for BoundaryType {
@@ -14,11 +35,16 @@ impl Default for BoundaryType {
 }
 
 impl BoundaryType {
-    /// Parse a boundary type from a string, returning None for invalid strings
+    /// Parse a boundary type from a string, returning None for invalid strings.
+    /// `"reflective"`/`"white"` parse to the default albedo of 1.0; use the
+    /// struct variants directly to set a different albedo.
     pub fn from_str_option(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
             "transmission" => Some(BoundaryType::Transmission),
             "vacuum" => Some(BoundaryType::Vacuum),
+            "reflective" => Some(BoundaryType::Reflective { albedo: DEFAULT_ALBEDO }),
+            "periodic" => Some(BoundaryType::Periodic),
+            "white" => Some(BoundaryType::White { albedo: DEFAULT_ALBEDO }),
             _ => None,
         }
     }
@@ -29,6 +55,11 @@ pub struct Surface {
     pub surface_id: usize,
     pub kind: SurfaceKind,
     pub boundary_type: BoundaryType,
+    /// Maps a world-space point back into this surface's local frame before
+    /// `evaluate` is applied, so that `Region::transformed` can reposition a
+    /// surface without mutating its coefficients. `None` means the surface
+    /// is already defined in world coordinates.
+    pub inverse_transform: Option<Transform>,
 }
 
 #[derive(Clone)]
@@ -36,6 +67,29 @@ pub enum SurfaceKind {
     Plane { a: f64, b: f64, c: f64, d: f64 },
     Sphere { x0: f64, y0: f64, z0: f64, radius: f64 },
     Cylinder { axis: [f64; 3], origin: [f64; 3], radius: f64 },
+    Triangle { v0: [f64; 3], v1: [f64; 3], v2: [f64; 3] },
+    /// A tessellated surface such as an imported STL. `bbox` is precomputed
+    /// from the vertices at construction time so ray queries can reject a
+    /// miss without walking every triangle.
+    Mesh { triangles: Vec<[[f64; 3]; 3]>, bbox: Option<crate::bounding_box::BoundingBox> },
+    /// A double-napped cone with its point at `apex`, opening along `axis`
+    /// (assumed unit length, as with `Cylinder`), with half-angle
+    /// `half_angle` (radians) measured from the axis.
+    Cone { apex: [f64; 3], axis: [f64; 3], half_angle: f64 },
+    /// The general second-order surface
+    /// `a*x^2 + b*y^2 + c*z^2 + d*x*y + e*y*z + f*x*z + g*x + h*y + j*z + k = 0`.
+    Quadric {
+        a: f64,
+        b: f64,
+        c: f64,
+        d: f64,
+        e: f64,
+        f: f64,
+        g: f64,
+        h: f64,
+        j: f64,
+        k: f64,
+    },
 }
 
 // Regular Rust implementation
@@ -45,6 +99,7 @@ impl Surface {
             surface_id,
             kind: SurfaceKind::Plane { a, b, c, d },
             boundary_type: boundary_type.unwrap_or_default(),
+            inverse_transform: None,
         }
     }
 
@@ -53,6 +108,7 @@ impl Surface {
             surface_id,
             kind: SurfaceKind::Sphere { x0, y0, z0, radius },
             boundary_type: boundary_type.unwrap_or_default(),
+            inverse_transform: None,
         }
     }
 
@@ -61,9 +117,92 @@ impl Surface {
             surface_id,
             kind: SurfaceKind::Cylinder { axis, origin, radius },
             boundary_type: boundary_type.unwrap_or_default(),
+            inverse_transform: None,
+        }
+    }
+
+    pub fn new_triangle(v0: [f64; 3], v1: [f64; 3], v2: [f64; 3], surface_id: usize, boundary_type: Option<BoundaryType>) -> Self {
+        Surface {
+            surface_id,
+            kind: SurfaceKind::Triangle { v0, v1, v2 },
+            boundary_type: boundary_type.unwrap_or_default(),
+            inverse_transform: None,
+        }
+    }
+
+    /// Create a mesh surface from a flat list of triangles (e.g. an imported
+    /// STL tessellation), precomputing its bounding box from the vertices.
+    pub fn new_mesh(triangles: Vec<[[f64; 3]; 3]>, surface_id: usize, boundary_type: Option<BoundaryType>) -> Self {
+        let bbox = mesh_bounding_box(&triangles);
+        Surface {
+            surface_id,
+            kind: SurfaceKind::Mesh { triangles, bbox },
+            boundary_type: boundary_type.unwrap_or_default(),
+            inverse_transform: None,
+        }
+    }
+
+    pub fn new_cone(apex: [f64; 3], axis: [f64; 3], half_angle: f64, surface_id: usize, boundary_type: Option<BoundaryType>) -> Self {
+        Surface {
+            surface_id,
+            kind: SurfaceKind::Cone { apex, axis, half_angle },
+            boundary_type: boundary_type.unwrap_or_default(),
+            inverse_transform: None,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_quadric(
+        a: f64,
+        b: f64,
+        c: f64,
+        d: f64,
+        e: f64,
+        f: f64,
+        g: f64,
+        h: f64,
+        j: f64,
+        k: f64,
+        surface_id: usize,
+        boundary_type: Option<BoundaryType>,
+    ) -> Self {
+        Surface {
+            surface_id,
+            kind: SurfaceKind::Quadric { a, b, c, d, e, f, g, h, j, k },
+            boundary_type: boundary_type.unwrap_or_default(),
+            inverse_transform: None,
         }
     }
-    
+
+    /// Return a copy of this surface repositioned by `t`: points are mapped
+    /// back through `t`'s inverse before being evaluated against the
+    /// surface's original (local-frame) coefficients.
+    pub fn transformed(&self, t: &Transform) -> Self {
+        let t_inv = t.inverse();
+        let composed = match &self.inverse_transform {
+            Some(existing) => existing.compose(&t_inv),
+            None => t_inv,
+        };
+        Surface {
+            surface_id: self.surface_id,
+            kind: self.kind.clone(),
+            boundary_type: self.boundary_type.clone(),
+            inverse_transform: Some(composed),
+        }
+    }
+
+    /// Rotate this surface by `matrix`, e.g. to orient a unit cell before
+    /// instancing it in a lattice.
+    pub fn rotate(&self, matrix: [[f64; 3]; 3]) -> Self {
+        self.transformed(&Transform::rotation(matrix))
+    }
+
+    /// Translate this surface by `offset`, e.g. to instance a unit cell at a
+    /// different lattice position.
+    pub fn translate(&self, offset: [f64; 3]) -> Self {
+        self.transformed(&Transform::translation(offset))
+    }
+
     pub fn x_plane(x0: f64, surface_id: usize, boundary_type: Option<BoundaryType>) -> Self {
         Self::new_plane(1.0, 0.0, 0.0, x0, surface_id, boundary_type)
     }
@@ -94,7 +233,7 @@ impl Surface {
     // Python-friendly functions that accept string boundary types
     pub fn x_plane_str(x0: f64, surface_id: usize, boundary_type: Option<&str>) -> Result<Self, String> {
         let boundary = match boundary_type {
-            Some(s) => Some(BoundaryType::from_str_option(s).ok_or("boundary_type must be 'transmission' or 'vacuum'")?),
+            Some(s) => Some(BoundaryType::from_str_option(s).ok_or(BOUNDARY_TYPE_ERROR)?),
             None => None,
         };
         Ok(Self::x_plane(x0, surface_id, boundary))
@@ -102,7 +241,7 @@ impl Surface {
 
     pub fn y_plane_str(y0: f64, surface_id: usize, boundary_type: Option<&str>) -> Result<Self, String> {
         let boundary = match boundary_type {
-            Some(s) => Some(BoundaryType::from_str_option(s).ok_or("boundary_type must be 'transmission' or 'vacuum'")?),
+            Some(s) => Some(BoundaryType::from_str_option(s).ok_or(BOUNDARY_TYPE_ERROR)?),
             None => None,
         };
         Ok(Self::y_plane(y0, surface_id, boundary))
@@ -110,7 +249,7 @@ impl Surface {
 
     pub fn z_plane_str(z0: f64, surface_id: usize, boundary_type: Option<&str>) -> Result<Self, String> {
         let boundary = match boundary_type {
-            Some(s) => Some(BoundaryType::from_str_option(s).ok_or("boundary_type must be 'transmission' or 'vacuum'")?),
+            Some(s) => Some(BoundaryType::from_str_option(s).ok_or(BOUNDARY_TYPE_ERROR)?),
             None => None,
         };
         Ok(Self::z_plane(z0, surface_id, boundary))
@@ -118,7 +257,7 @@ impl Surface {
 
     pub fn sphere_str(x0: f64, y0: f64, z0: f64, radius: f64, surface_id: usize, boundary_type: Option<&str>) -> Result<Self, String> {
         let boundary = match boundary_type {
-            Some(s) => Some(BoundaryType::from_str_option(s).ok_or("boundary_type must be 'transmission' or 'vacuum'")?),
+            Some(s) => Some(BoundaryType::from_str_option(s).ok_or(BOUNDARY_TYPE_ERROR)?),
             None => None,
         };
         Ok(Self::sphere(x0, y0, z0, radius, surface_id, boundary))
@@ -126,7 +265,7 @@ impl Surface {
 
     pub fn cylinder_str(x0: f64, y0: f64, z0: f64, axis_x: f64, axis_y: f64, axis_z: f64, radius: f64, surface_id: usize, boundary_type: Option<&str>) -> Result<Self, String> {
         let boundary = match boundary_type {
-            Some(s) => Some(BoundaryType::from_str_option(s).ok_or("boundary_type must be 'transmission' or 'vacuum'")?),
+            Some(s) => Some(BoundaryType::from_str_option(s).ok_or(BOUNDARY_TYPE_ERROR)?),
             None => None,
         };
         Ok(Self::cylinder(x0, y0, z0, axis_x, axis_y, axis_z, radius, surface_id, boundary))
@@ -134,7 +273,7 @@ impl Surface {
 
     pub fn z_cylinder_str(x0: f64, y0: f64, radius: f64, surface_id: usize, boundary_type: Option<&str>) -> Result<Self, String> {
         let boundary = match boundary_type {
-            Some(s) => Some(BoundaryType::from_str_option(s).ok_or("boundary_type must be 'transmission' or 'vacuum'")?),
+            Some(s) => Some(BoundaryType::from_str_option(s).ok_or(BOUNDARY_TYPE_ERROR)?),
             None => None,
         };
         Ok(Self::z_cylinder(x0, y0, radius, surface_id, boundary))
@@ -142,12 +281,42 @@ impl Surface {
 
     pub fn plane_str(a: f64, b: f64, c: f64, d: f64, surface_id: usize, boundary_type: Option<&str>) -> Result<Self, String> {
         let boundary = match boundary_type {
-            Some(s) => Some(BoundaryType::from_str_option(s).ok_or("boundary_type must be 'transmission' or 'vacuum'")?),
+            Some(s) => Some(BoundaryType::from_str_option(s).ok_or(BOUNDARY_TYPE_ERROR)?),
             None => None,
         };
         Ok(Self::new_plane(a, b, c, d, surface_id, boundary))
     }
 
+    pub fn cone_str(apex: [f64; 3], axis: [f64; 3], half_angle: f64, surface_id: usize, boundary_type: Option<&str>) -> Result<Self, String> {
+        let boundary = match boundary_type {
+            Some(s) => Some(BoundaryType::from_str_option(s).ok_or(BOUNDARY_TYPE_ERROR)?),
+            None => None,
+        };
+        Ok(Self::new_cone(apex, axis, half_angle, surface_id, boundary))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn quadric_str(
+        a: f64,
+        b: f64,
+        c: f64,
+        d: f64,
+        e: f64,
+        f: f64,
+        g: f64,
+        h: f64,
+        j: f64,
+        k: f64,
+        surface_id: usize,
+        boundary_type: Option<&str>,
+    ) -> Result<Self, String> {
+        let boundary = match boundary_type {
+            Some(s) => Some(BoundaryType::from_str_option(s).ok_or(BOUNDARY_TYPE_ERROR)?),
+            None => None,
+        };
+        Ok(Self::new_quadric(a, b, c, d, e, f, g, h, j, k, surface_id, boundary))
+    }
+
     /// Get the boundary type of the surface
     pub fn boundary_type(&self) -> &BoundaryType {
         &self.boundary_type
@@ -159,6 +328,13 @@ impl Surface {
     }
 
     pub fn evaluate(&self, point: (f64, f64, f64)) -> f64 {
+        let point = match &self.inverse_transform {
+            Some(t) => {
+                let p = t.apply_point([point.0, point.1, point.2]);
+                (p[0], p[1], p[2])
+            }
+            None => point,
+        };
         match &self.kind {
             SurfaceKind::Plane { a, b, c, d } => {
                 a * point.0 + b * point.1 + c * point.2 - d
@@ -167,7 +343,7 @@ impl Surface {
                 let dx = point.0 - x0;
                 let dy = point.1 - y0;
                 let dz = point.2 - z0;
-                (dx * dx + dy * dy + dz * dz).sqrt() - radius
+                crate::ops::sqrt(dx * dx + dy * dy + dz * dz) - radius
             }
             SurfaceKind::Cylinder { axis, origin, radius } => {
                 let v = [point.0 - origin[0], point.1 - origin[1], point.2 - origin[2]];
@@ -177,12 +353,252 @@ impl Surface {
                     v[1] - dot * axis[1],
                     v[2] - dot * axis[2],
                 ];
-                (d[0] * d[0] + d[1] * d[1] + d[2] * d[2]).sqrt() - radius
+                crate::ops::sqrt(d[0] * d[0] + d[1] * d[1] + d[2] * d[2]) - radius
+            }
+            SurfaceKind::Triangle { v0, v1, v2 } => {
+                let p = [point.0, point.1, point.2];
+                let normal = triangle_normal(*v0, *v1, *v2);
+                dot(sub(p, *v0), normal)
+            }
+            SurfaceKind::Mesh { triangles, bbox } => {
+                let p = [point.0, point.1, point.2];
+                let distance = triangles
+                    .iter()
+                    .map(|tri| distance_to_triangle(p, tri[0], tri[1], tri[2]))
+                    .fold(f64::INFINITY, f64::min);
+                let inside = match bbox {
+                    Some(bbox) if !bbox.contains_point(point) => false,
+                    _ => mesh_contains(triangles, p),
+                };
+                if inside {
+                    -distance
+                } else {
+                    distance
+                }
+            }
+            SurfaceKind::Cone { apex, axis, half_angle } => {
+                let v = [point.0 - apex[0], point.1 - apex[1], point.2 - apex[2]];
+                let axial = dot(v, *axis);
+                let cos_half_angle = crate::ops::sin_cos(*half_angle).1;
+                axial * axial - cos_half_angle * cos_half_angle * dot(v, v)
+            }
+            SurfaceKind::Quadric { a, b, c, d, e, f, g, h, j, k } => {
+                let (x, y, z) = point;
+                a * x * x + b * y * y + c * z * z + d * x * y + e * y * z + f * x * z + g * x + h * y + j * z + k
+            }
+        }
+    }
+
+    /// The outward unit normal to this surface at `point`, which is assumed
+    /// to lie on (or very near) the surface.
+    pub fn normal(&self, point: (f64, f64, f64)) -> (f64, f64, f64) {
+        let local_point = match &self.inverse_transform {
+            Some(t) => {
+                let p = t.apply_point([point.0, point.1, point.2]);
+                (p[0], p[1], p[2])
+            }
+            None => point,
+        };
+        let local_normal = match &self.kind {
+            SurfaceKind::Plane { a, b, c, .. } => normalize([*a, *b, *c]),
+            SurfaceKind::Sphere { x0, y0, z0, .. } => {
+                normalize([local_point.0 - x0, local_point.1 - y0, local_point.2 - z0])
+            }
+            SurfaceKind::Cylinder { axis, origin, .. } => {
+                let v = [
+                    local_point.0 - origin[0],
+                    local_point.1 - origin[1],
+                    local_point.2 - origin[2],
+                ];
+                let along_axis = dot(v, *axis);
+                normalize(sub(v, scale(*axis, along_axis)))
+            }
+            SurfaceKind::Triangle { v0, v1, v2 } => triangle_normal(*v0, *v1, *v2),
+            SurfaceKind::Mesh { triangles, .. } => {
+                let p = [local_point.0, local_point.1, local_point.2];
+                triangles
+                    .iter()
+                    .min_by(|tri_a, tri_b| {
+                        let dist_a = distance_to_triangle(p, tri_a[0], tri_a[1], tri_a[2]);
+                        let dist_b = distance_to_triangle(p, tri_b[0], tri_b[1], tri_b[2]);
+                        dist_a.partial_cmp(&dist_b).unwrap()
+                    })
+                    .map(|tri| triangle_normal(tri[0], tri[1], tri[2]))
+                    .unwrap_or([0.0, 0.0, 0.0])
+            }
+            SurfaceKind::Cone { apex, axis, half_angle } => {
+                let v = [
+                    local_point.0 - apex[0],
+                    local_point.1 - apex[1],
+                    local_point.2 - apex[2],
+                ];
+                let axial = dot(v, *axis);
+                let cos_half_angle = crate::ops::sin_cos(*half_angle).1;
+                // Gradient of `(v.axis)^2 - cos^2(theta)*|v|^2` w.r.t. v.
+                normalize(sub(scale(*axis, axial), scale(v, cos_half_angle * cos_half_angle)))
+            }
+            SurfaceKind::Quadric { a, b, c, d, e, f, g, h, j, .. } => {
+                let (x, y, z) = local_point;
+                normalize([
+                    2.0 * a * x + d * y + f * z + g,
+                    2.0 * b * y + d * x + e * z + h,
+                    2.0 * c * z + e * y + f * x + j,
+                ])
+            }
+        };
+        match &self.inverse_transform {
+            // Normals transform by the inverse-transpose of the forward map,
+            // i.e. the transpose of `t`'s (world-to-local) linear part, which
+            // keeps them perpendicular under a non-uniform scale too;
+            // re-normalize since a scale would otherwise leave them non-unit.
+            Some(t) => {
+                let m = &t.matrix;
+                let n = local_normal;
+                let world_normal = normalize([
+                    m[0][0] * n[0] + m[1][0] * n[1] + m[2][0] * n[2],
+                    m[0][1] * n[0] + m[1][1] * n[1] + m[2][1] * n[2],
+                    m[0][2] * n[0] + m[1][2] * n[1] + m[2][2] * n[2],
+                ]);
+                (world_normal[0], world_normal[1], world_normal[2])
+            }
+            None => (local_normal[0], local_normal[1], local_normal[2]),
+        }
+    }
+
+    /// Reflect `direction` off this surface at `point`: d' = d - 2(d·n)n.
+    /// Used so a particle that has just been advanced onto a reflective
+    /// boundary can be bounced back into the cell it came from.
+    pub fn reflect(&self, point: (f64, f64, f64), direction: (f64, f64, f64)) -> (f64, f64, f64) {
+        let n = self.normal(point);
+        let n = [n.0, n.1, n.2];
+        let d = [direction.0, direction.1, direction.2];
+        let reflected = sub(d, scale(n, 2.0 * dot(d, n)));
+        (reflected[0], reflected[1], reflected[2])
+    }
+
+    /// Distance along `direction` from `point` to the nearest (positive)
+    /// crossing of this surface, or `None` if `direction` never meets it.
+    pub fn distance_to_surface(&self, point: [f64; 3], direction: [f64; 3]) -> Option<f64> {
+        let ray = crate::region::Ray {
+            origin: (point[0], point[1], point[2]),
+            direction: (direction[0], direction[1], direction[2]),
+        };
+        crate::region::surface_intersections(self, &ray)
+            .into_iter()
+            .filter(|t| *t > EPSILON)
+            .min_by(|a, b| a.partial_cmp(b).unwrap())
+    }
+
+    /// The bounding box of the `inside` (negative, "below") halfspace of this
+    /// surface, or the complementary "above" halfspace when `inside` is
+    /// `false`. Returns `None` for the outside of a sphere or cylinder, a
+    /// non-axis-aligned plane or cylinder, and a cone or quadric. A triangle
+    /// gives the box of its three vertices and a mesh its precomputed vertex
+    /// box for either value of `inside`; the triangle's halfspaces and a
+    /// mesh's outside extend beyond that box. For a surface with an
+    /// `inverse_transform`, a finite local box is mapped into world space
+    /// and a partly unbounded one gives `None`.
+    pub fn bounding_box(&self, inside: bool) -> Option<([f64; 3], [f64; 3])> {
+        let local = self.local_bounding_box(inside);
+        match &self.inverse_transform {
+            None => local,
+            Some(t) => {
+                let (lower, upper) = local?;
+                if !lower.iter().chain(upper.iter()).all(|v| v.is_finite()) {
+                    return None;
+                }
+                let world = crate::bounding_box::BoundingBox::new(lower, upper).transformed(&t.inverse());
+                Some((world.lower_left_corner, world.upper_right_corner))
+            }
+        }
+    }
+
+    fn local_bounding_box(&self, inside: bool) -> Option<([f64; 3], [f64; 3])> {
+        match &self.kind {
+            SurfaceKind::Plane { a, b, c, d } => {
+                let (axis, coeff) = axis_aligned_coefficient(*a, *b, *c)?;
+                let threshold = *d / coeff;
+                let mut lower = [f64::NEG_INFINITY; 3];
+                let mut upper = [f64::INFINITY; 3];
+                let wants_greater = if coeff > 0.0 { !inside } else { inside };
+                if wants_greater {
+                    lower[axis] = threshold;
+                } else {
+                    upper[axis] = threshold;
+                }
+                Some((lower, upper))
+            }
+            SurfaceKind::Sphere { x0, y0, z0, radius } if inside => Some((
+                [*x0 - radius, *y0 - radius, *z0 - radius],
+                [*x0 + radius, *y0 + radius, *z0 + radius],
+            )),
+            SurfaceKind::Cylinder { axis, origin, radius } if inside => {
+                let along = axis_aligned_unit_axis(*axis)?;
+                let mut lower = [f64::NEG_INFINITY; 3];
+                let mut upper = [f64::INFINITY; 3];
+                for perp in 0..3 {
+                    if perp != along {
+                        lower[perp] = origin[perp] - radius;
+                        upper[perp] = origin[perp] + radius;
+                    }
+                }
+                Some((lower, upper))
+            }
+            SurfaceKind::Triangle { v0, v1, v2 } => Some((
+                [v0[0].min(v1[0]).min(v2[0]), v0[1].min(v1[1]).min(v2[1]), v0[2].min(v1[2]).min(v2[2])],
+                [v0[0].max(v1[0]).max(v2[0]), v0[1].max(v1[1]).max(v2[1]), v0[2].max(v1[2]).max(v2[2])],
+            )),
+            SurfaceKind::Mesh { bbox: Some(bbox), .. } => {
+                Some((bbox.lower_left_corner, bbox.upper_right_corner))
+            }
+            _ => None,
+        }
+    }
+
+    /// If this surface, used as a halfspace, constrains a single axis (i.e.
+    /// it's an axis-aligned plane), return `(axis_index, is_upper_bound,
+    /// value)`: `axis_index` is 0/1/2 for X/Y/Z, and `is_upper_bound` is
+    /// `true` if `halfspace_below` constrains that axis from above. Returns
+    /// `None` for every other surface kind or orientation, and for any
+    /// surface with an `inverse_transform`.
+    pub fn axis_constraint(&self, halfspace_below: bool) -> Option<(usize, bool, f64)> {
+        if self.inverse_transform.is_some() {
+            return None;
+        }
+        match &self.kind {
+            SurfaceKind::Plane { a, b, c, d } => {
+                let (axis, coeff) = axis_aligned_coefficient(*a, *b, *c)?;
+                let threshold = *d / coeff;
+                let is_upper_bound = if coeff > 0.0 { halfspace_below } else { !halfspace_below };
+                Some((axis, is_upper_bound, threshold))
             }
+            _ => None,
         }
     }
 }
 
+/// If `(a, b, c)` has exactly one nonzero component, return its axis index
+/// (0/1/2 for X/Y/Z) and that component's (possibly negative) coefficient.
+fn axis_aligned_coefficient(a: f64, b: f64, c: f64) -> Option<(usize, f64)> {
+    match (a != 0.0, b != 0.0, c != 0.0) {
+        (true, false, false) => Some((0, a)),
+        (false, true, false) => Some((1, b)),
+        (false, false, true) => Some((2, c)),
+        _ => None,
+    }
+}
+
+/// If `axis` points along exactly one coordinate axis (either direction),
+/// return that axis's index.
+fn axis_aligned_unit_axis(axis: [f64; 3]) -> Option<usize> {
+    match (axis[0] != 0.0, axis[1] != 0.0, axis[2] != 0.0) {
+        (true, false, false) => Some(0),
+        (false, true, false) => Some(1),
+        (false, false, true) => Some(2),
+        _ => None,
+    }
+}
+
 #[derive(Clone)]
 pub struct Halfspace {
     pub expr: RegionExpr,
@@ -202,6 +618,166 @@ impl Halfspace {
     }
 }
 
+/// Matches `region::EPSILON`; kept separate since the two modules don't share
+/// a common numerics module for a single tolerance constant.
+const EPSILON: f64 = 1e-9;
+
+fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn add(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn scale(a: [f64; 3], s: f64) -> [f64; 3] {
+    [a[0] * s, a[1] * s, a[2] * s]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn normalize(a: [f64; 3]) -> [f64; 3] {
+    let len = crate::ops::sqrt(dot(a, a));
+    [a[0] / len, a[1] / len, a[2] / len]
+}
+
+/// The outward normal of the plane spanned by a triangle's vertices, following
+/// the right-hand rule from `v0 -> v1 -> v2`.
+fn triangle_normal(v0: [f64; 3], v1: [f64; 3], v2: [f64; 3]) -> [f64; 3] {
+    normalize(cross(sub(v1, v0), sub(v2, v0)))
+}
+
+fn mesh_bounding_box(triangles: &[[[f64; 3]; 3]]) -> Option<crate::bounding_box::BoundingBox> {
+    if triangles.is_empty() {
+        return None;
+    }
+    let mut lower = [f64::INFINITY; 3];
+    let mut upper = [f64::NEG_INFINITY; 3];
+    for triangle in triangles {
+        for vertex in triangle {
+            for axis in 0..3 {
+                lower[axis] = lower[axis].min(vertex[axis]);
+                upper[axis] = upper[axis].max(vertex[axis]);
+            }
+        }
+    }
+    Some(crate::bounding_box::BoundingBox::new(lower, upper))
+}
+
+/// Möller–Trumbore ray/triangle intersection: the distance along the ray from
+/// `origin` to the triangle `(v0, v1, v2)`, or `None` if the ray is parallel
+/// to the triangle's plane, misses it, or would hit behind the origin.
+pub(crate) fn moller_trumbore(
+    origin: [f64; 3],
+    direction: [f64; 3],
+    v0: [f64; 3],
+    v1: [f64; 3],
+    v2: [f64; 3],
+) -> Option<f64> {
+    let e1 = sub(v1, v0);
+    let e2 = sub(v2, v0);
+    let h = cross(direction, e2);
+    let a = dot(e1, h);
+    if a.abs() < EPSILON {
+        return None;
+    }
+    let f = 1.0 / a;
+    let s = sub(origin, v0);
+    let u = f * dot(s, h);
+    if u < 0.0 || u > 1.0 {
+        return None;
+    }
+    let q = cross(s, e1);
+    let v = f * dot(direction, q);
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let t = f * dot(e2, q);
+    if t > EPSILON {
+        Some(t)
+    } else {
+        None
+    }
+}
+
+/// Whether `point` is enclosed by a closed triangle mesh, via ray-casting
+/// parity: an odd number of crossings along a fixed ray means the point is
+/// inside. The ray direction is skewed off-axis to dodge edge/vertex grazes.
+fn mesh_contains(triangles: &[[[f64; 3]; 3]], point: [f64; 3]) -> bool {
+    let direction = [1.0, 1e-3, 1e-4];
+    let crossings = triangles
+        .iter()
+        .filter(|tri| moller_trumbore(point, direction, tri[0], tri[1], tri[2]).is_some())
+        .count();
+    crossings % 2 == 1
+}
+
+/// The closest point on triangle `(a, b, c)` to `p` (Ericson, *Real-Time
+/// Collision Detection*): checks the three vertex regions, then the three
+/// edge regions, then falls back to the face's barycentric interior.
+fn closest_point_on_triangle(p: [f64; 3], a: [f64; 3], b: [f64; 3], c: [f64; 3]) -> [f64; 3] {
+    let ab = sub(b, a);
+    let ac = sub(c, a);
+    let ap = sub(p, a);
+    let d1 = dot(ab, ap);
+    let d2 = dot(ac, ap);
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return a;
+    }
+
+    let bp = sub(p, b);
+    let d3 = dot(ab, bp);
+    let d4 = dot(ac, bp);
+    if d3 >= 0.0 && d4 <= d3 {
+        return b;
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        let v = d1 / (d1 - d3);
+        return add(a, scale(ab, v));
+    }
+
+    let cp = sub(p, c);
+    let d5 = dot(ab, cp);
+    let d6 = dot(ac, cp);
+    if d6 >= 0.0 && d5 <= d6 {
+        return c;
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        let w = d2 / (d2 - d6);
+        return add(a, scale(ac, w));
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+        let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return add(b, scale(sub(c, b), w));
+    }
+
+    let denom = 1.0 / (va + vb + vc);
+    let v = vb * denom;
+    let w = vc * denom;
+    add(a, add(scale(ab, v), scale(ac, w)))
+}
+
+fn distance_to_triangle(p: [f64; 3], a: [f64; 3], b: [f64; 3], c: [f64; 3]) -> f64 {
+    let closest = closest_point_on_triangle(p, a, b, c);
+    crate::ops::sqrt(dot(sub(p, closest), sub(p, closest)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -289,4 +865,245 @@ mod tests {
         cylinder.set_boundary_type(BoundaryType::Vacuum);
         assert_eq!(*cylinder.boundary_type(), BoundaryType::Vacuum);
     }
+
+    #[test]
+    fn test_boundary_type_from_str_reflective_white_periodic() {
+        assert_eq!(
+            BoundaryType::from_str_option("reflective"),
+            Some(BoundaryType::Reflective { albedo: 1.0 })
+        );
+        assert_eq!(
+            BoundaryType::from_str_option("white"),
+            Some(BoundaryType::White { albedo: 1.0 })
+        );
+        assert_eq!(BoundaryType::from_str_option("periodic"), Some(BoundaryType::Periodic));
+        assert_eq!(BoundaryType::from_str_option("nonsense"), None);
+    }
+
+    #[test]
+    fn test_triangle_evaluate_sign() {
+        // Triangle in the z=0 plane; normal points toward +z.
+        let tri = Surface::new_triangle(
+            [0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            1,
+            None,
+        );
+        assert!(tri.evaluate((0.2, 0.2, 1.0)) > 0.0);
+        assert!(tri.evaluate((0.2, 0.2, -1.0)) < 0.0);
+    }
+
+    fn unit_cube_mesh() -> Surface {
+        // Two triangles per face of the unit cube [0,1]^3; winding order
+        // doesn't matter since `evaluate` only needs crossing parity.
+        let c = |x: f64, y: f64, z: f64| [x, y, z];
+        let faces = [
+            // -x / +x
+            [c(0.0, 0.0, 0.0), c(0.0, 1.0, 0.0), c(0.0, 1.0, 1.0)],
+            [c(0.0, 0.0, 0.0), c(0.0, 1.0, 1.0), c(0.0, 0.0, 1.0)],
+            [c(1.0, 0.0, 0.0), c(1.0, 1.0, 0.0), c(1.0, 1.0, 1.0)],
+            [c(1.0, 0.0, 0.0), c(1.0, 1.0, 1.0), c(1.0, 0.0, 1.0)],
+            // -y / +y
+            [c(0.0, 0.0, 0.0), c(1.0, 0.0, 0.0), c(1.0, 0.0, 1.0)],
+            [c(0.0, 0.0, 0.0), c(1.0, 0.0, 1.0), c(0.0, 0.0, 1.0)],
+            [c(0.0, 1.0, 0.0), c(1.0, 1.0, 0.0), c(1.0, 1.0, 1.0)],
+            [c(0.0, 1.0, 0.0), c(1.0, 1.0, 1.0), c(0.0, 1.0, 1.0)],
+            // -z / +z
+            [c(0.0, 0.0, 0.0), c(1.0, 0.0, 0.0), c(1.0, 1.0, 0.0)],
+            [c(0.0, 0.0, 0.0), c(1.0, 1.0, 0.0), c(0.0, 1.0, 0.0)],
+            [c(0.0, 0.0, 1.0), c(1.0, 0.0, 1.0), c(1.0, 1.0, 1.0)],
+            [c(0.0, 0.0, 1.0), c(1.0, 1.0, 1.0), c(0.0, 1.0, 1.0)],
+        ];
+        Surface::new_mesh(faces.to_vec(), 2, None)
+    }
+
+    #[test]
+    fn test_mesh_evaluate_inside_outside() {
+        let mesh = unit_cube_mesh();
+        assert!(mesh.evaluate((0.5, 0.5, 0.5)) < 0.0);
+        assert!(mesh.evaluate((2.0, 2.0, 2.0)) > 0.0);
+    }
+
+    #[test]
+    fn test_plane_normal() {
+        let plane = Surface::new_plane(1.0, 0.0, 0.0, 2.0, 1, None);
+        assert_eq!(plane.normal((2.0, 5.0, -1.0)), (1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_sphere_normal() {
+        let sphere = Surface::new_sphere(0.0, 0.0, 0.0, 2.0, 1, None);
+        let n = sphere.normal((2.0, 0.0, 0.0));
+        assert!((n.0 - 1.0).abs() < 1e-9);
+        assert!(n.1.abs() < 1e-9);
+        assert!(n.2.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_reflect_off_plane() {
+        // Plane x = 2, particle travelling in +x reflects to -x.
+        let plane = Surface::new_plane(1.0, 0.0, 0.0, 2.0, 1, None);
+        let reflected = plane.reflect((2.0, 0.0, 0.0), (1.0, 0.0, 0.0));
+        assert!((reflected.0 - (-1.0)).abs() < 1e-9);
+        assert!(reflected.1.abs() < 1e-9);
+        assert!(reflected.2.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_reflect_off_sphere_grazing_angle_preserved() {
+        // A direction tangent to the sphere at the hit point reflects unchanged.
+        let sphere = Surface::new_sphere(0.0, 0.0, 0.0, 2.0, 1, None);
+        let reflected = sphere.reflect((2.0, 0.0, 0.0), (0.0, 1.0, 0.0));
+        assert!((reflected.0 - 0.0).abs() < 1e-9);
+        assert!((reflected.1 - 1.0).abs() < 1e-9);
+        assert!(reflected.2.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_mesh_bounding_box_from_vertices() {
+        let mesh = unit_cube_mesh();
+        match mesh.kind {
+            SurfaceKind::Mesh { bbox: Some(bbox), .. } => {
+                assert_eq!(bbox.lower_left_corner, [0.0, 0.0, 0.0]);
+                assert_eq!(bbox.upper_right_corner, [1.0, 1.0, 1.0]);
+            }
+            _ => panic!("Expected a mesh with a precomputed bounding box"),
+        }
+    }
+
+    #[test]
+    fn test_cone_evaluate_sign() {
+        // Apex at the origin, opening along +z with a 45 degree half-angle.
+        let cone = Surface::new_cone(
+            [0.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0],
+            std::f64::consts::FRAC_PI_4,
+            1,
+            None,
+        );
+        assert!(cone.evaluate((0.0, 0.0, 2.0)) > 0.0);
+        assert!(cone.evaluate((5.0, 0.0, 2.0)) < 0.0);
+    }
+
+    #[test]
+    fn test_cone_normal() {
+        let cone = Surface::new_cone(
+            [0.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0],
+            std::f64::consts::FRAC_PI_4,
+            1,
+            None,
+        );
+        // (1, 0, 1) sits on the cone's surface at this half-angle.
+        let n = cone.normal((1.0, 0.0, 1.0));
+        assert!((n.0 - (-std::f64::consts::FRAC_1_SQRT_2)).abs() < 1e-9);
+        assert!(n.1.abs() < 1e-9);
+        assert!((n.2 - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_quadric_evaluate_sign() {
+        // x^2 + y^2 + z^2 - 4 = 0 is a sphere of radius 2 written as a quadric.
+        let quadric = Surface::new_quadric(1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -4.0, 1, None);
+        assert!(quadric.evaluate((0.0, 0.0, 0.0)) < 0.0);
+        assert!(quadric.evaluate((3.0, 0.0, 0.0)) > 0.0);
+    }
+
+    #[test]
+    fn test_translate_moves_plane() {
+        let plane = Surface::new_plane(1.0, 0.0, 0.0, 0.0, 1, None);
+        let moved = plane.translate([3.0, 0.0, 0.0]);
+        // The plane x = 0 translated by +3 in x becomes x = 3 in world space.
+        assert!(moved.evaluate((3.0, 5.0, -2.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rotate_moves_cylinder_axis() {
+        // A cylinder along +z, rotated 90 degrees about the y axis, now runs
+        // along +x.
+        let cylinder = Surface::new_cylinder([0.0, 0.0, 1.0], [0.0, 0.0, 0.0], 1.0, 1, None);
+        let rotated = cylinder.rotate([
+            [0.0, 0.0, 1.0],
+            [0.0, 1.0, 0.0],
+            [-1.0, 0.0, 0.0],
+        ]);
+        // A point on the new axis (the old +x world axis) is inside the shell.
+        assert!(rotated.evaluate((5.0, 0.0, 0.0)) < 0.0);
+        // A point at radius 1 from that axis sits exactly on the surface.
+        assert!(rotated.evaluate((5.0, 1.0, 0.0)).abs() < 1e-9);
+        // A point further out is outside the shell.
+        assert!(rotated.evaluate((5.0, 2.0, 0.0)) > 0.0);
+    }
+
+    #[test]
+    fn test_quadric_normal() {
+        let quadric = Surface::new_quadric(1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -4.0, 1, None);
+        let n = quadric.normal((2.0, 0.0, 0.0));
+        assert!((n.0 - 1.0).abs() < 1e-9);
+        assert!(n.1.abs() < 1e-9);
+        assert!(n.2.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_distance_to_surface_sphere() {
+        let sphere = Surface::new_sphere(0.0, 0.0, 0.0, 2.0, 1, None);
+        let hit = sphere.distance_to_surface([-5.0, 0.0, 0.0], [1.0, 0.0, 0.0]);
+        assert!((hit.unwrap() - 3.0).abs() < 1e-9);
+
+        let miss = sphere.distance_to_surface([-5.0, 10.0, 0.0], [1.0, 0.0, 0.0]);
+        assert!(miss.is_none());
+
+        // Both candidate crossings are behind the origin: no positive distance.
+        let behind = sphere.distance_to_surface([5.0, 0.0, 0.0], [1.0, 0.0, 0.0]);
+        assert!(behind.is_none());
+    }
+
+    #[test]
+    fn test_bounding_box_axis_aligned_plane_and_negative_coefficient() {
+        let above_x2 = Surface::new_plane(1.0, 0.0, 0.0, 2.0, 1, None);
+        let (lower, upper) = above_x2.bounding_box(false).unwrap();
+        assert_eq!(lower, [2.0, f64::NEG_INFINITY, f64::NEG_INFINITY]);
+        assert_eq!(upper, [f64::INFINITY; 3]);
+
+        // "x > 2" written as -x < -2 (the inside of the negated plane) must
+        // give the same bounding box.
+        let negated = Surface::new_plane(-1.0, 0.0, 0.0, -2.0, 2, None);
+        let (neg_lower, neg_upper) = negated.bounding_box(true).unwrap();
+        assert_eq!(neg_lower, lower);
+        assert_eq!(neg_upper, upper);
+
+        // A tilted plane has no box-shaped halfspace at all.
+        let tilted = Surface::new_plane(1.0, 1.0, 0.0, 0.0, 3, None);
+        assert!(tilted.bounding_box(true).is_none());
+    }
+
+    #[test]
+    fn test_bounding_box_sphere_and_cone() {
+        let sphere = Surface::new_sphere(1.0, 2.0, 3.0, 4.0, 1, None);
+        let (lower, upper) = sphere.bounding_box(true).unwrap();
+        assert_eq!(lower, [-3.0, -2.0, -1.0]);
+        assert_eq!(upper, [5.0, 6.0, 7.0]);
+        // Outside a sphere is unbounded in every direction.
+        assert!(sphere.bounding_box(false).is_none());
+
+        let cone = Surface::new_cone([0.0, 0.0, 0.0], [0.0, 0.0, 1.0], 0.5, 2, None);
+        assert!(cone.bounding_box(true).is_none());
+        assert!(cone.bounding_box(false).is_none());
+    }
+
+    #[test]
+    fn test_axis_constraint_plane_only() {
+        let plane = Surface::new_plane(1.0, 0.0, 0.0, 2.0, 1, None);
+        assert_eq!(plane.axis_constraint(true), Some((0, true, 2.0)));
+        assert_eq!(plane.axis_constraint(false), Some((0, false, 2.0)));
+
+        let negated = Surface::new_plane(-1.0, 0.0, 0.0, -2.0, 2, None);
+        // "below -x < -2" is the same "x > 2" constraint as "above x < 2" on
+        // the non-negated plane: a lower bound, not an upper one.
+        assert_eq!(negated.axis_constraint(true), Some((0, false, 2.0)));
+
+        let sphere = Surface::new_sphere(0.0, 0.0, 0.0, 1.0, 3, None);
+        assert!(sphere.axis_constraint(true).is_none());
+    }
 }
@@ -14,25 +14,45 @@ pub struct PyBoundaryType {
 
 #[pymethods]
 impl PyBoundaryType {
+    /// `albedo` only applies to `"reflective"`/`"white"`; it's ignored (and
+    /// may be omitted) for every other boundary type.
     #[new]
-    fn new(boundary_type: &str) -> PyResult<Self> {
-        let boundary = BoundaryType::from_str_option(boundary_type)
+    fn new(boundary_type: &str, albedo: Option<f64>) -> PyResult<Self> {
+        let mut boundary = BoundaryType::from_str_option(boundary_type)
             .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                "boundary_type must be 'transmission' or 'vacuum'"
+                crate::surface::BOUNDARY_TYPE_ERROR
             ))?;
+        if let Some(albedo) = albedo {
+            match &mut boundary {
+                BoundaryType::Reflective { albedo: a } | BoundaryType::White { albedo: a } => *a = albedo,
+                _ => {}
+            }
+        }
         Ok(PyBoundaryType { inner: boundary })
     }
 
-    fn __str__(&self) -> &str {
+    fn __str__(&self) -> String {
         match self.inner {
-            BoundaryType::Transmission => "transmission",
-            BoundaryType::Vacuum => "vacuum",
+            BoundaryType::Transmission => "transmission".to_string(),
+            BoundaryType::Vacuum => "vacuum".to_string(),
+            BoundaryType::Reflective { albedo } => format!("reflective(albedo={albedo})"),
+            BoundaryType::Periodic => "periodic".to_string(),
+            BoundaryType::White { albedo } => format!("white(albedo={albedo})"),
         }
     }
 
     fn __repr__(&self) -> String {
         format!("BoundaryType('{}')", self.__str__())
     }
+
+    /// The albedo for a reflective/white boundary, or `None` for any other type.
+    #[getter]
+    fn albedo(&self) -> Option<f64> {
+        match self.inner {
+            BoundaryType::Reflective { albedo } | BoundaryType::White { albedo } => Some(albedo),
+            _ => None,
+        }
+    }
 }
 
 #[pyclass(name = "Surface")]
@@ -54,6 +74,28 @@ impl PySurface {
         self.inner.evaluate(point)
     }
 
+    /// The unit outward normal at `point`.
+    pub fn normal(&self, point: (f64, f64, f64)) -> (f64, f64, f64) {
+        self.inner.normal(point)
+    }
+
+    /// The specular reflection of `direction` off this surface at `point`.
+    pub fn reflect(&self, point: (f64, f64, f64), direction: (f64, f64, f64)) -> (f64, f64, f64) {
+        self.inner.reflect(point, direction)
+    }
+
+    /// A copy of this surface rotated by `matrix`, e.g. to orient a unit cell
+    /// before instancing it in a lattice.
+    pub fn rotate(&self, matrix: [[f64; 3]; 3]) -> PySurface {
+        PySurface { inner: self.inner.rotate(matrix) }
+    }
+
+    /// A copy of this surface translated by `offset`, e.g. to instance a unit
+    /// cell at a different lattice position.
+    pub fn translate(&self, offset: [f64; 3]) -> PySurface {
+        PySurface { inner: self.inner.translate(offset) }
+    }
+
     /// Get the bounding box for the inside (negative halfspace) of this surface
     pub fn bounding_box_inside(&self) -> Option<PyBoundingBox> {
         self.inner.bounding_box(true).map(|(lower, upper)| PyBoundingBox {
@@ -111,12 +153,37 @@ impl PySurface {
     pub fn set_boundary_type(&mut self, boundary_type: &str) -> PyResult<()> {
         let boundary = BoundaryType::from_str_option(boundary_type)
             .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                "boundary_type must be 'transmission' or 'vacuum'"
+                crate::surface::BOUNDARY_TYPE_ERROR
             ))?;
         self.inner.set_boundary_type(boundary);
         Ok(())
     }
 
+    /// The albedo of this surface's boundary, or `None` if its boundary type
+    /// isn't reflective/white.
+    #[getter]
+    pub fn albedo(&self) -> Option<f64> {
+        match self.inner.boundary_type() {
+            BoundaryType::Reflective { albedo } | BoundaryType::White { albedo } => Some(*albedo),
+            _ => None,
+        }
+    }
+
+    /// Set the albedo of this surface's boundary. Errors if the current
+    /// boundary type isn't reflective/white, since only those carry an albedo.
+    #[setter(albedo)]
+    pub fn set_albedo(&mut self, albedo: f64) -> PyResult<()> {
+        match &mut self.inner.boundary_type {
+            BoundaryType::Reflective { albedo: a } | BoundaryType::White { albedo: a } => {
+                *a = albedo;
+                Ok(())
+            }
+            _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "albedo only applies to reflective or white boundary types",
+            )),
+        }
+    }
+
     fn __neg__(slf: PyRef<'_, Self>) -> PyResult<PyHalfspace> {
         let py = slf.py();
         let py_surface: Py<PySurface> = slf.into_py(py).extract(py).unwrap();
@@ -187,4 +254,21 @@ pub fn Plane(a: f64, b: f64, c: f64, d: f64, surface_id: Option<usize>, boundary
     Ok(PySurface { inner: surface })
 }
 
+#[pyfunction]
+#[allow(non_snake_case)]
+pub fn Cone(apex_x: f64, apex_y: f64, apex_z: f64, axis_x: f64, axis_y: f64, axis_z: f64, half_angle: f64, surface_id: usize, boundary_type: Option<&str>) -> PyResult<PySurface> {
+    let surface = Surface::cone_str([apex_x, apex_y, apex_z], [axis_x, axis_y, axis_z], half_angle, surface_id, boundary_type)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+    Ok(PySurface { inner: surface })
+}
+
+#[pyfunction]
+#[allow(non_snake_case)]
+#[allow(clippy::too_many_arguments)]
+pub fn Quadric(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64, g: f64, h: f64, j: f64, k: f64, surface_id: usize, boundary_type: Option<&str>) -> PyResult<PySurface> {
+    let surface = Surface::quadric_str(a, b, c, d, e, f, g, h, j, k, surface_id, boundary_type)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+    Ok(PySurface { inner: surface })
+}
+
 
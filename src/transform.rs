@@ -0,0 +1,153 @@
+/// An affine transform: a 3x3 rotation/scale matrix plus a translation vector.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Transform {
+    pub matrix: [[f64; 3]; 3],
+    pub translation: [f64; 3],
+}
+
+impl Transform {
+    pub fn identity() -> Self {
+        Transform {
+            matrix: [
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 0.0, 1.0],
+            ],
+            translation: [0.0, 0.0, 0.0],
+        }
+    }
+
+    pub fn translation(translation: [f64; 3]) -> Self {
+        Transform {
+            matrix: Self::identity().matrix,
+            translation,
+        }
+    }
+
+    pub fn rotation(matrix: [[f64; 3]; 3]) -> Self {
+        Transform {
+            matrix,
+            translation: [0.0, 0.0, 0.0],
+        }
+    }
+
+    /// Map a point from this transform's input frame to its output frame.
+    pub fn apply_point(&self, p: [f64; 3]) -> [f64; 3] {
+        let m = &self.matrix;
+        [
+            m[0][0] * p[0] + m[0][1] * p[1] + m[0][2] * p[2] + self.translation[0],
+            m[1][0] * p[0] + m[1][1] * p[1] + m[1][2] * p[2] + self.translation[1],
+            m[2][0] * p[0] + m[2][1] * p[1] + m[2][2] * p[2] + self.translation[2],
+        ]
+    }
+
+    /// Map a direction (e.g. a surface axis) without applying the translation.
+    pub fn apply_vector(&self, v: [f64; 3]) -> [f64; 3] {
+        let m = &self.matrix;
+        [
+            m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+            m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+            m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+        ]
+    }
+
+    fn determinant(&self) -> f64 {
+        let m = &self.matrix;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// The inverse transform. Falls back to the identity for a (near-)singular
+    /// matrix rather than panicking, since geometry queries elsewhere in the
+    /// crate are infallible.
+    pub fn inverse(&self) -> Transform {
+        let det = self.determinant();
+        if det.abs() < 1e-12 {
+            return Transform::identity();
+        }
+        let m = &self.matrix;
+        let inv_det = 1.0 / det;
+        let inv_matrix = [
+            [
+                (m[1][1] * m[2][2] - m[1][2] * m[2][1]) * inv_det,
+                (m[0][2] * m[2][1] - m[0][1] * m[2][2]) * inv_det,
+                (m[0][1] * m[1][2] - m[0][2] * m[1][1]) * inv_det,
+            ],
+            [
+                (m[1][2] * m[2][0] - m[1][0] * m[2][2]) * inv_det,
+                (m[0][0] * m[2][2] - m[0][2] * m[2][0]) * inv_det,
+                (m[0][2] * m[1][0] - m[0][0] * m[1][2]) * inv_det,
+            ],
+            [
+                (m[1][0] * m[2][1] - m[1][1] * m[2][0]) * inv_det,
+                (m[0][1] * m[2][0] - m[0][0] * m[2][1]) * inv_det,
+                (m[0][0] * m[1][1] - m[0][1] * m[1][0]) * inv_det,
+            ],
+        ];
+        let t = &self.translation;
+        let inv_translation = [
+            -(inv_matrix[0][0] * t[0] + inv_matrix[0][1] * t[1] + inv_matrix[0][2] * t[2]),
+            -(inv_matrix[1][0] * t[0] + inv_matrix[1][1] * t[1] + inv_matrix[1][2] * t[2]),
+            -(inv_matrix[2][0] * t[0] + inv_matrix[2][1] * t[1] + inv_matrix[2][2] * t[2]),
+        ];
+        Transform {
+            matrix: inv_matrix,
+            translation: inv_translation,
+        }
+    }
+
+    /// Compose two transforms: applying the result is the same as applying
+    /// `other` first, then `self`.
+    pub fn compose(&self, other: &Transform) -> Transform {
+        let a = &self.matrix;
+        let b = &other.matrix;
+        let mut matrix = [[0.0; 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                matrix[i][j] = a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j];
+            }
+        }
+        let translation = self.apply_vector(other.translation);
+        Transform {
+            matrix,
+            translation: [
+                translation[0] + self.translation[0],
+                translation[1] + self.translation[1],
+                translation[2] + self.translation[2],
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translation_round_trip() {
+        let t = Transform::translation([1.0, 2.0, 3.0]);
+        let p = t.apply_point([0.0, 0.0, 0.0]);
+        assert_eq!(p, [1.0, 2.0, 3.0]);
+        let back = t.inverse().apply_point(p);
+        assert!((back[0]).abs() < 1e-9);
+        assert!((back[1]).abs() < 1e-9);
+        assert!((back[2]).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_compose_translation_and_rotation() {
+        // 90 degree rotation about Z, then translate by (1,0,0).
+        let rotate = Transform::rotation([
+            [0.0, -1.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0],
+        ]);
+        let translate = Transform::translation([1.0, 0.0, 0.0]);
+        let combined = translate.compose(&rotate);
+        let p = combined.apply_point([1.0, 0.0, 0.0]);
+        assert!((p[0] - 1.0).abs() < 1e-9);
+        assert!((p[1] - 1.0).abs() < 1e-9);
+        assert!((p[2] - 0.0).abs() < 1e-9);
+    }
+}